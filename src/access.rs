@@ -0,0 +1,199 @@
+use crate::errors::{
+    ERR_INVALID_MARKET, ERR_NOT_ORACLE, ERR_ORACLE_ASSIGNED, ERR_ORACLE_QUORUM, ERR_ROLE_ALREADY_GRANTED,
+    ERR_ROLE_NOT_GRANTED,
+};
+use crate::types::{MarketStatus, Role, TimelockAction};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait AccessModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule +
+    crate::timelock::TimelockModule
+{
+    #[only_owner]
+    #[endpoint(addOracle)]
+    fn add_oracle(&self, address: ManagedAddress) -> u64 {
+        self.queue_grant_role(Role::Oracle, address)
+    }
+
+    /// Refused while it would break the quorum or leave a market awaiting
+    /// its result without the oracle assigned to it.
+    #[only_owner]
+    #[endpoint(removeOracle)]
+    fn remove_oracle(&self, address: ManagedAddress) {
+        require!(
+            self.role_members(&Role::Oracle).len() > self.get_oracle_quorum() as usize,
+            ERR_ORACLE_QUORUM
+        );
+        for market_id in self.oracle_assigned_markets(&address).iter() {
+            let status = self.markets(market_id).get().market_status;
+            require!(
+                status == MarketStatus::Settled || status == MarketStatus::Voided,
+                ERR_ORACLE_ASSIGNED
+            );
+        }
+
+        self.revoke_role(Role::Oracle, &address);
+        self.oracle_assigned_markets(&address).clear();
+    }
+
+    #[only_owner]
+    #[endpoint(addOperator)]
+    fn add_operator(&self, address: ManagedAddress) -> u64 {
+        self.queue_grant_role(Role::Operator, address)
+    }
+
+    #[only_owner]
+    #[endpoint(removeOperator)]
+    fn remove_operator(&self, address: ManagedAddress) {
+        self.revoke_role(Role::Operator, &address);
+    }
+
+    #[only_owner]
+    #[endpoint(addGuardian)]
+    fn add_guardian(&self, address: ManagedAddress) -> u64 {
+        self.queue_grant_role(Role::Guardian, address)
+    }
+
+    #[only_owner]
+    #[endpoint(removeGuardian)]
+    fn remove_guardian(&self, address: ManagedAddress) {
+        self.revoke_role(Role::Guardian, &address);
+    }
+
+    #[only_owner]
+    #[endpoint(addMarketCreator)]
+    fn add_market_creator(&self, address: ManagedAddress) -> u64 {
+        self.queue_grant_role(Role::MarketCreator, address)
+    }
+
+    #[only_owner]
+    #[endpoint(removeMarketCreator)]
+    fn remove_market_creator(&self, address: ManagedAddress) {
+        self.revoke_role(Role::MarketCreator, &address);
+    }
+
+    #[only_owner]
+    #[endpoint(addTreasuryManager)]
+    fn add_treasury_manager(&self, address: ManagedAddress) -> u64 {
+        self.queue_grant_role(Role::TreasuryManager, address)
+    }
+
+    #[only_owner]
+    #[endpoint(removeTreasuryManager)]
+    fn remove_treasury_manager(&self, address: ManagedAddress) {
+        self.revoke_role(Role::TreasuryManager, &address);
+    }
+
+    /// Queues requiring a registered oracle's report, on top of the quorum,
+    /// before a market's result is accepted.
+    #[only_owner]
+    #[endpoint(assignMarketOracle)]
+    fn assign_market_oracle(&self, market_id: u64, oracle: ManagedAddress) -> u64 {
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+        require!(self.role_members(&Role::Oracle).contains(&oracle), ERR_ROLE_NOT_GRANTED);
+
+        self.queue_action(TimelockAction::AssignMarketOracle(market_id, oracle))
+    }
+
+    #[only_owner]
+    #[endpoint(unassignMarketOracle)]
+    fn unassign_market_oracle(&self, market_id: u64) -> u64 {
+        require!(!self.market_oracle(market_id).is_empty(), ERR_INVALID_MARKET);
+        self.queue_action(TimelockAction::UnassignMarketOracle(market_id))
+    }
+
+    /// Grants take effect after the timelock; revocations apply at once so a
+    /// compromised address can be cut off immediately.
+    fn queue_grant_role(&self, role: Role, address: ManagedAddress) -> u64 {
+        require!(!self.has_role(role, &address), ERR_ROLE_ALREADY_GRANTED);
+        self.queue_action(TimelockAction::GrantRole(role, address))
+    }
+
+    fn revoke_role(&self, role: Role, address: &ManagedAddress) {
+        require!(self.role_members(&role).swap_remove(address), ERR_ROLE_NOT_GRANTED);
+        self.role_revoked_event(role, address);
+    }
+
+    fn has_role(&self, role: Role, address: &ManagedAddress) -> bool {
+        self.role_members(&role).contains(address)
+    }
+
+    fn require_owner_or_role(&self, role: Role, error: &str) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == self.blockchain().get_owner_address() || self.has_role(role, &caller),
+            error
+        );
+        caller
+    }
+
+    fn require_oracle(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(self.has_role(Role::Oracle, &caller), ERR_NOT_ORACLE);
+        caller
+    }
+
+    #[view(getRoles)]
+    fn get_roles(&self, address: ManagedAddress) -> MultiValueEncoded<Role> {
+        let mut result = MultiValueEncoded::new();
+        for role in [
+            Role::Oracle,
+            Role::Operator,
+            Role::Guardian,
+            Role::MarketCreator,
+            Role::TreasuryManager,
+        ] {
+            if self.has_role(role, &address) {
+                result.push(role);
+            }
+        }
+        result
+    }
+
+    #[view(getOracles)]
+    fn get_oracles(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        for oracle in self.role_members(&Role::Oracle).iter() {
+            result.push(oracle);
+        }
+        result
+    }
+
+    #[view(getOperators)]
+    fn get_operators(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        for operator in self.role_members(&Role::Operator).iter() {
+            result.push(operator);
+        }
+        result
+    }
+
+    #[view(getGuardians)]
+    fn get_guardians(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut result = MultiValueEncoded::new();
+        for guardian in self.role_members(&Role::Guardian).iter() {
+            result.push(guardian);
+        }
+        result
+    }
+
+    #[view(getOracleQuorum)]
+    fn get_oracle_quorum(&self) -> u32 {
+        if self.oracle_quorum().is_empty() {
+            return 1;
+        }
+        self.oracle_quorum().get()
+    }
+
+    #[view(getMarketOracle)]
+    fn get_market_oracle(&self, market_id: u64) -> OptionalValue<ManagedAddress> {
+        if self.market_oracle(market_id).is_empty() {
+            return OptionalValue::None;
+        }
+        OptionalValue::Some(self.market_oracle(market_id).get())
+    }
+}
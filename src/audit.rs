@@ -0,0 +1,150 @@
+use crate::errors::ERR_INVALID_MARKET;
+use crate::types::{AuditCheck, Bet, BetStatus, BetType, MarketStatus, SolvencyReport};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait AuditModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule +
+    crate::nft::NftModule +
+    crate::tokens::TokensModule +
+    crate::fund::FundModule +
+    crate::access::AccessModule +
+    crate::pause::PauseModule +
+    crate::timelock::TimelockModule
+{
+    /// Compares everything the contract owes in `token` with what it holds.
+    /// Unsettled markets owe the escrow of their bets; settled markets owe the
+    /// payouts of bets that have not been processed yet. Challenge bonds, keeper
+    /// bounties and the treasury are held on top. Fully processed markets owe
+    /// nothing, so only the active market index is walked.
+    #[view(getSolvencyReport)]
+    fn get_solvency_report(&self, token: EgldOrEsdtTokenIdentifier) -> SolvencyReport<Self::Api> {
+        let mut unmatched_stakes = BigUint::zero();
+        let mut matched_liabilities = BigUint::zero();
+        let mut unsettled_payouts = BigUint::zero();
+        let mut held_bonds = BigUint::zero();
+        let mut keeper_bounties = BigUint::zero();
+
+        for market_id in self.active_markets().iter() {
+            if token.is_egld() && !self.market_challenge(market_id).is_empty() {
+                held_bonds += self.market_challenge(market_id).get().bond;
+            }
+
+            let market = self.markets(market_id).get();
+            if market.settlement_token != token {
+                continue;
+            }
+            keeper_bounties += self.keeper_bounty(market_id).get();
+
+            for bet_id in self.market_bet_ids(market_id).iter() {
+                let bet = self.bet_by_id(bet_id).get();
+                let locked = self.bet_locked_amount(&bet);
+                let matched_locked = self.matched_locked_amount(&bet);
+                unmatched_stakes += locked - &matched_locked;
+
+                if market.market_status != MarketStatus::Settled {
+                    matched_liabilities += matched_locked;
+                } else if bet.status == BetStatus::Matched {
+                    let outcome = self.selection_outcome(market_id, bet.selection.id);
+                    unsettled_payouts += self.calculate_settlement_payout(&bet, outcome);
+                }
+            }
+        }
+
+        let treasury = self.treasury_balance(&token).get();
+        let total_obligations = &unmatched_stakes
+            + &matched_liabilities
+            + &unsettled_payouts
+            + &held_bonds
+            + &keeper_bounties
+            + &treasury;
+        let contract_balance = self.blockchain().get_sc_balance(&token, 0);
+
+        SolvencyReport {
+            token,
+            is_solvent: contract_balance >= total_obligations,
+            unmatched_stakes,
+            matched_liabilities,
+            unsettled_payouts,
+            held_bonds,
+            keeper_bounties,
+            treasury,
+            total_obligations,
+            contract_balance,
+        }
+    }
+
+    /// Recomputes liquidity and matched totals from the market's bets and
+    /// emits an event for every stored figure that disagrees.
+    #[only_owner]
+    #[endpoint(auditMarket)]
+    fn audit_market(&self, market_id: u64) -> u32 {
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+        let market = self.markets(market_id).get();
+
+        let mut discrepancies = 0u32;
+        let mut market_matched = BigUint::zero();
+        for selection in market.selections.iter() {
+            let mut back_unmatched = BigUint::zero();
+            let mut lay_unmatched = BigUint::zero();
+            let mut selection_matched = BigUint::zero();
+
+            for bet_id in self.market_bet_ids(market_id).iter() {
+                let bet = self.bet_by_id(bet_id).get();
+                if bet.selection.id != selection.id || bet.status == BetStatus::Pending {
+                    continue;
+                }
+
+                match bet.bet_type {
+                    BetType::Back => {
+                        back_unmatched += &bet.unmatched_amount;
+                        selection_matched += &bet.matched_amount;
+                    },
+                    BetType::Lay => lay_unmatched += &bet.unmatched_amount,
+                }
+            }
+            market_matched += &selection_matched;
+
+            let checks = [
+                (AuditCheck::BackLiquidity, self.selection_back_liquidity(market_id, selection.id).get(), back_unmatched),
+                (AuditCheck::LayLiquidity, self.selection_lay_liquidity(market_id, selection.id).get(), lay_unmatched),
+                (AuditCheck::SelectionMatched, self.total_matched_amount(market_id, selection.id).get(), selection_matched),
+            ];
+
+            for (check, recorded, recomputed) in checks.iter() {
+                if recorded != recomputed {
+                    self.audit_discrepancy_event(market_id, selection.id, *check, recorded, recomputed);
+                    discrepancies += 1;
+                }
+            }
+        }
+
+        if market.total_matched_amount != market_matched {
+            self.audit_discrepancy_event(
+                market_id,
+                0,
+                AuditCheck::MarketMatched,
+                &market.total_matched_amount,
+                &market_matched
+            );
+            discrepancies += 1;
+        }
+
+        self.market_audited_event(market_id, discrepancies);
+        discrepancies
+    }
+
+    fn matched_locked_amount(&self, bet: &Bet<Self::Api>) -> BigUint {
+        if self.bet_locked_amount(bet) == BigUint::zero() {
+            return BigUint::zero();
+        }
+
+        match bet.bet_type {
+            BetType::Back => bet.matched_amount.clone(),
+            BetType::Lay => &bet.matched_amount + &self.liability_share(bet, &bet.matched_amount),
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use crate::types::{Bet, BetStatus, BetType};
+use crate::errors::ERR_INVALID_ROLE;
+use crate::types::{Bet, BetStatus, BetType, MarketStatus, PauseScope};
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
@@ -7,8 +8,14 @@ pub trait BetModule:
     crate::storage::StorageModule +
     crate::events::EventsModule +
     crate::nft::NftModule +
+    crate::fund::FundModule +
+    crate::access::AccessModule +
+    crate::pause::PauseModule +
+    crate::limits::LimitsModule +
     crate::tracker::TrackerModule +
-    crate::validation::ValidationModule 
+    crate::validation::ValidationModule +
+    crate::tokens::TokensModule +
+    crate::timelock::TimelockModule
 {
     #[payable("*")]
     #[endpoint(placeBet)]
@@ -27,10 +34,15 @@ pub trait BetModule:
             .egld_or_single_esdt()
             .into_tuple();
 
-        self.validate_bet_amount(&total_amount);
-        self.validate_bet_odds(&odds);
+        self.require_not_paused(PauseScope::Betting);
+        self.require_storage_migrated();
         self.validate_market(market_id);
+        self.validate_market_token(market_id, &token_identifier, token_nonce);
         self.validate_selection(market_id, selection_id);
+
+        let limits = self.get_effective_limits(caller.clone(), market_id);
+        self.validate_bet_amount(&token_identifier, &total_amount, &limits);
+        self.validate_bet_odds(&odds, &limits);
         
         let (final_stake, final_liability) = self.calculate_stake_and_liability(
             &bet_type,
@@ -50,6 +62,21 @@ pub trait BetModule:
             token_identifier.clone(),
             token_nonce
         );
+        let exposure = self.to_normalized_amount(&token_identifier, &self.bet_exposure(&bet));
+        self.validate_user_exposure(&caller, &exposure, &limits);
+
+        if self.is_in_play_phase(market_id) {
+            let pending_bet = self.queue_pending_bet(bet);
+            self.handle_nft_and_locked_funds(cid, &caller, &pending_bet);
+            self.emit_bet_placed_event(
+                &pending_bet,
+                &token_identifier,
+                token_nonce,
+                &BigUint::zero(),
+                &pending_bet.unmatched_amount
+            );
+            return;
+        }
 
         let (matched_amount, unmatched_amount) = self.process_bet(bet.clone());
         let updated_bet = self.update_bet_status(bet, matched_amount.clone(), unmatched_amount.clone());
@@ -59,14 +86,7 @@ pub trait BetModule:
             &matched_amount
         );
 
-        self.handle_nft_and_locked_funds(
-            cid,
-            &caller,
-            &updated_bet,
-            &unmatched_amount,
-            &final_liability,
-            bet_type
-        );
+        self.handle_nft_and_locked_funds(cid, &caller, &updated_bet);
 
         self.emit_bet_placed_event(
             &updated_bet,
@@ -157,24 +177,94 @@ pub trait BetModule:
         self.markets(market_id).set(&market);
     }
 
+    /// Matches in-play bets whose delay has elapsed. A bet is refunded instead
+    /// if the market was suspended at any point after it was placed.
+    #[endpoint(processPendingBets)]
+    fn process_pending_bets(&self, market_id: u64, max_bets: u64) -> u64 {
+        self.require_not_paused(PauseScope::Betting);
+        let now = self.blockchain().get_block_timestamp();
+        let mut processed = 0u64;
+
+        while processed < max_bets {
+            let bet_id = match self.pending_bets(market_id).front() {
+                Some(bet_id) => bet_id,
+                None => break,
+            };
+            if self.bet_activation_time(bet_id).get() > now {
+                break;
+            }
+
+            self.pending_bets(market_id).pop_front();
+            self.bet_activation_time(bet_id).clear();
+            processed += 1;
+
+            let mut bet = self.bet_by_id(bet_id).get();
+            if bet.status != BetStatus::Pending {
+                continue;
+            }
+
+            let market_status = self.markets(market_id).get().market_status;
+            if market_status != MarketStatus::Open
+                || now >= self.betting_close_timestamp(market_id)
+                || self.market_suspended_at(market_id).get() >= bet.created_at
+            {
+                self.process_unmatched_bet(bet_id);
+                continue;
+            }
+
+            bet.status = BetStatus::Unmatched;
+            let selection_id = bet.selection.id;
+            let (matched_amount, unmatched_amount) = self.process_bet(bet.clone());
+            let updated_bet = self.update_bet_status(bet, matched_amount.clone(), unmatched_amount.clone());
+            self.bet_by_id(bet_id).set(&updated_bet);
+            self.update_market_and_selection(market_id, selection_id, &matched_amount);
+
+            self.pending_bet_activated_event(bet_id, &matched_amount, &unmatched_amount);
+        }
+        processed
+    }
+
+    fn queue_pending_bet(&self, mut bet: Bet<Self::Api>) -> Bet<Self::Api> {
+        let bet_delay = self.market_in_play(bet.event).get().bet_delay;
+        let activation_time = bet.created_at + bet_delay;
+
+        bet.status = BetStatus::Pending;
+        self.bet_activation_time(bet.nft_nonce).set(activation_time);
+        self.pending_bets(bet.event).push_back(bet.nft_nonce);
+
+        self.bet_pending_event(bet.nft_nonce, activation_time);
+        bet
+    }
+
+    /// Only the original bettor can cancel; the endpoint takes no payment, so
+    /// holding the bet NFT is not enough.
+    #[endpoint(cancelBet)]
+    fn cancel_bet(&self, bet_id: u64) {
+        let bet = self.get_bet(bet_id);
+        require!(self.blockchain().get_caller() == bet.bettor, ERR_INVALID_ROLE);
+        require!(bet.unmatched_amount > BigUint::zero(), "Nothing to cancel");
+        let market_id = bet.event;
+        let market_status = self.markets(market_id).get().market_status;
+        require!(
+            market_status == MarketStatus::Open || market_status == MarketStatus::Suspended,
+            "Market not open"
+        );
+
+        self.remove_from_orderbook(&bet);
+        self.process_unmatched_bet(bet_id);
+    }
+
     fn handle_nft_and_locked_funds(
         &self,
         cid: ManagedBuffer,
         caller: &ManagedAddress<Self::Api>,
-        bet: &Bet<Self::Api>,
-        unmatched_amount: &BigUint,
-        liability: &BigUint,
-        bet_type: BetType
+        bet: &Bet<Self::Api>
     ) {
         let bet_nft_nonce = self.mint_bet_nft(cid, bet);
         self.bet_by_id(bet.nft_nonce).set(bet);
 
         self.market_bet_ids(bet.event).insert(bet.nft_nonce);
-        let total_locked = match bet_type {
-            BetType::Back => unmatched_amount.clone(),
-            BetType::Lay => liability.clone(),
-        };
-        self.locked_funds(caller).update(|current_locked| *current_locked += &total_locked);
+        self.lock_bet_funds(bet);
 
         self.send().direct_esdt(
             caller,
@@ -10,11 +10,25 @@ pub mod constants {
     //Bet
     pub const MIN_ODDS: u32 = 101;      // 1.01
     pub const MAX_ODDS: u32 = 100000;   // 1000.00
+    pub const ODDS_BASE: u32 = 100;     // 1.00
+    pub const TOKEN_UNIT: u64 = 1_000_000_000_000_000_000; // 1 EGLD
+    pub const MIN_STAKE_TOKENS: u64 = 1;
+    pub const MAX_STAKE_TOKENS: u64 = 10_000;
+    pub const NORMALIZED_DECIMALS: u32 = 18;
 
     // Market constants
     pub const MAX_SELECTIONS: usize = 100;
+    pub const FULL_TIME_RESULT_TYPE: u64 = 1;
+    pub const TOTAL_GOALS_TYPE: u64 = 2;
+    pub const BOTH_TEAMS_TO_SCORE_TYPE: u64 = 3;
+    pub const DEFAULT_RESULT_TIMEOUT: u64 = 604_800; // 7 days after close
+    pub const MAX_RESULT_TIMEOUT: u64 = 2_592_000; // 30 days after close
+    pub const SECONDS_PER_HOUR: u64 = 3_600;
+
+    // Admin constants
+    pub const STORAGE_VERSION: u32 = 1;
     
     // User constants
-    pub const MAX_USER_EXPOSURE: u64 = 10_000_000_000_000_000_000; // 10 EGLD
+    pub const MAX_EXPOSURE_TOKENS: u64 = 100_000; // ten maximum stakes
 }
 
@@ -25,3 +25,50 @@ pub const ERR_INVALID_ROLE: &str = "Unauthorized! Invalid Role";
 pub const ERR_INVALID_TIMESTAMP: &str = "Close timestamp must be in the future";
 
 pub const ERR_MAXIMUM_STAKE: &str = "Exceeds maximum user exposure limit";
+pub const ERR_INVALID_LIMITS: &str = "Invalid limits configuration";
+pub const ERR_TOKEN_NOT_ACCEPTED: &str = "Payment token not accepted";
+pub const ERR_INVALID_TOKEN_CONFIG: &str = "Invalid token configuration";
+pub const ERR_INVALID_MARKET_TOKEN: &str = "Payment token does not match the market settlement token";
+pub const ERR_NOT_ORACLE: &str = "Caller is not an oracle for this market";
+pub const ERR_ROLE_ALREADY_GRANTED: &str = "Address already has this role";
+pub const ERR_ROLE_NOT_GRANTED: &str = "Address does not have this role";
+pub const ERR_ALREADY_REPORTED: &str = "Oracle already reported a result for this market";
+pub const ERR_INVALID_QUORUM: &str = "Invalid oracle quorum";
+pub const ERR_MARKET_NOT_PROPOSED: &str = "Market result is not awaiting confirmation";
+pub const ERR_CHALLENGE_WINDOW_CLOSED: &str = "Challenge window has closed";
+pub const ERR_CHALLENGE_WINDOW_OPEN: &str = "Challenge window is still open";
+pub const ERR_ALREADY_CHALLENGED: &str = "Result already challenged";
+pub const ERR_NOT_CHALLENGED: &str = "Result has not been challenged";
+pub const ERR_INVALID_BOND: &str = "Invalid challenge bond";
+pub const ERR_RESULT_DEADLINE_NOT_REACHED: &str = "Result deadline not reached";
+pub const ERR_INVALID_RESULT_DEADLINE: &str = "Result deadline can only be extended, up to the maximum result timeout";
+pub const ERR_RESULT_DEADLINE_PASSED: &str = "Result deadline has passed";
+pub const ERR_INVALID_RESULT_TIMEOUT: &str = "Invalid result timeout";
+pub const ERR_NOT_OPERATOR: &str = "Caller is not an operator";
+pub const ERR_MARKET_NOT_SUSPENDED: &str = "Market is not suspended";
+pub const ERR_INVALID_IN_PLAY: &str = "In-play must end after kickoff with a non-zero bet delay";
+pub const ERR_NOT_GUARDIAN: &str = "Caller is not a guardian";
+pub const ERR_BETTING_PAUSED: &str = "Betting is paused";
+pub const ERR_SETTLEMENT_PAUSED: &str = "Settlement is paused";
+pub const ERR_CLAIMS_PAUSED: &str = "Claims are paused";
+pub const ERR_NOT_MARKET_CREATOR: &str = "Caller is not a market creator";
+pub const ERR_NOT_TREASURY_MANAGER: &str = "Caller is not a treasury manager";
+pub const ERR_INSUFFICIENT_TREASURY: &str = "Amount exceeds the treasury balance";
+pub const ERR_MARKET_NOT_VOIDABLE: &str = "Market is already settled or voided";
+pub const ERR_ACTION_NOT_QUEUED: &str = "No queued action with this id";
+pub const ERR_TIMELOCK_NOT_EXPIRED: &str = "Timelock has not expired";
+pub const ERR_INVALID_TIMELOCK_DELAY: &str = "Timelock delay must be greater than zero";
+pub const ERR_MARKET_TYPE_EXISTS: &str = "Market type already registered";
+pub const ERR_UNKNOWN_MARKET_TYPE: &str = "Unknown market type";
+pub const ERR_INVALID_MARKET_TYPE: &str = "Invalid resolution rule or selection labels";
+pub const ERR_INCOMPLETE_REPORT: &str = "Result report is missing data this market needs";
+pub const ERR_INVALID_OUTCOME: &str = "Winning selections must be valid ids in ascending order";
+pub const ERR_INVALID_EVENT: &str = "Event doesn't exist!";
+pub const ERR_EVENT_NOT_SCHEDULED: &str = "Event is not scheduled";
+pub const ERR_EVENT_HAS_MARKETS: &str = "Kickoff of an event with markets cannot be changed here";
+pub const ERR_NO_MARKETS_TO_RESULT: &str = "No closed market of this event accepts the report";
+pub const ERR_STORAGE_NOT_MIGRATED: &str = "Storage migration has not finished";
+pub const ERR_STORAGE_MIGRATED: &str = "Storage is already migrated";
+pub const ERR_LEGACY_MARKET_TYPE: &str = "Legacy market has no matching market type";
+pub const ERR_ORACLE_QUORUM: &str = "Removing this oracle would leave fewer oracles than the quorum";
+pub const ERR_ORACLE_ASSIGNED: &str = "Oracle is assigned to a market awaiting its result";
@@ -0,0 +1,200 @@
+use crate::constants::constants;
+use crate::errors::{
+    ERR_EVENT_HAS_MARKETS, ERR_EVENT_NOT_SCHEDULED, ERR_INVALID_EVENT, ERR_INVALID_TIMESTAMP, ERR_NOT_MARKET_CREATOR,
+    ERR_NOT_OPERATOR,
+};
+use crate::types::{EventStatus, MarketStatus, PostponePolicy, Role, SportEvent};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait EventRegistryModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule +
+    crate::nft::NftModule +
+    crate::tokens::TokensModule +
+    crate::fund::FundModule +
+    crate::access::AccessModule +
+    crate::pause::PauseModule +
+    crate::timelock::TimelockModule
+{
+    #[endpoint(createEvent)]
+    fn create_event(
+        &self,
+        home_team: ManagedBuffer,
+        away_team: ManagedBuffer,
+        competition_id: u64,
+        sport: ManagedBuffer,
+        kickoff_timestamp: u64
+    ) -> u64 {
+        self.require_owner_or_role(Role::MarketCreator, ERR_NOT_MARKET_CREATOR);
+        require!(
+            kickoff_timestamp > self.blockchain().get_block_timestamp(),
+            ERR_INVALID_TIMESTAMP
+        );
+
+        let event_id = self.event_counter().get() + 1;
+        self.event_counter().set(event_id);
+
+        self.sport_events(event_id).set(&SportEvent {
+            event_id,
+            home_team,
+            away_team,
+            competition_id,
+            sport,
+            kickoff_timestamp,
+            status: EventStatus::Scheduled,
+            created_at: self.blockchain().get_block_timestamp(),
+        });
+
+        self.sport_event_created_event(event_id, competition_id, kickoff_timestamp);
+        event_id
+    }
+
+    /// Corrects event details. Kickoff can only change here before any market
+    /// is listed; afterwards it is moved with postponeEvent.
+    #[endpoint(updateEvent)]
+    fn update_event(
+        &self,
+        event_id: u64,
+        home_team: ManagedBuffer,
+        away_team: ManagedBuffer,
+        competition_id: u64,
+        sport: ManagedBuffer,
+        kickoff_timestamp: u64
+    ) {
+        self.require_owner_or_role(Role::MarketCreator, ERR_NOT_MARKET_CREATOR);
+        let mut event = self.require_scheduled_event(event_id);
+
+        if kickoff_timestamp != event.kickoff_timestamp {
+            require!(self.markets_by_event(event_id).get().is_empty(), ERR_EVENT_HAS_MARKETS);
+            require!(
+                kickoff_timestamp > self.blockchain().get_block_timestamp(),
+                ERR_INVALID_TIMESTAMP
+            );
+        }
+
+        event.home_team = home_team;
+        event.away_team = away_team;
+        event.competition_id = competition_id;
+        event.sport = sport;
+        event.kickoff_timestamp = kickoff_timestamp;
+        self.sport_events(event_id).set(&event);
+
+        self.sport_event_updated_event(event_id, kickoff_timestamp);
+    }
+
+    /// Moves kickoff back and applies the policy to the event's markets. Markets
+    /// still taking bets keep their offset to kickoff; closed ones keep betting
+    /// closed but get a later result deadline.
+    #[endpoint(postponeEvent)]
+    fn postpone_event(&self, event_id: u64, new_kickoff: u64, policy: PostponePolicy) {
+        self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        let mut event = self.require_scheduled_event(event_id);
+
+        let old_kickoff = event.kickoff_timestamp;
+        require!(
+            new_kickoff > old_kickoff && new_kickoff > self.blockchain().get_block_timestamp(),
+            ERR_INVALID_TIMESTAMP
+        );
+        let delay = new_kickoff - old_kickoff;
+        let void = match policy {
+            PostponePolicy::VoidAfter(hours) => delay > hours * constants::SECONDS_PER_HOUR,
+            _ => false,
+        };
+
+        event.kickoff_timestamp = new_kickoff;
+        self.sport_events(event_id).set(&event);
+
+        for market_id in self.markets_by_event(event_id).get().iter() {
+            let market = self.markets(market_id).get();
+            match market.market_status {
+                MarketStatus::Settled | MarketStatus::Voided => continue,
+                _ if void => self.void_market(market_id),
+                MarketStatus::Open | MarketStatus::Suspended => {
+                    if policy == PostponePolicy::CancelUnmatched {
+                        self.process_unmatched_bets(market_id);
+                    }
+                    self.move_market_close(market_id, market.close_timestamp + delay);
+                },
+                _ => self.market_result_deadline(market_id).update(|deadline| *deadline += delay),
+            }
+        }
+
+        self.sport_event_postponed_event(event_id, old_kickoff, new_kickoff, policy, void);
+    }
+
+    /// Cancels the event and voids every market under it that is not settled yet.
+    #[endpoint(cancelEvent)]
+    fn cancel_event(&self, event_id: u64) {
+        self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        let mut event = self.require_scheduled_event(event_id);
+
+        event.status = EventStatus::Canceled;
+        self.sport_events(event_id).set(&event);
+
+        let mut voided_markets = 0u32;
+        for market_id in self.markets_by_event(event_id).get().iter() {
+            let status = self.markets(market_id).get().market_status;
+            if status == MarketStatus::Settled || status == MarketStatus::Voided {
+                continue;
+            }
+
+            self.void_market(market_id);
+            voided_markets += 1;
+        }
+
+        self.sport_event_canceled_event(event_id, voided_markets);
+    }
+
+    /// Sets a new close time, moving the in-play window and result deadline
+    /// by the same amount.
+    fn move_market_close(&self, market_id: u64, new_close_timestamp: u64) {
+        let mut market = self.markets(market_id).get();
+        let old_close_timestamp = market.close_timestamp;
+        market.close_timestamp = new_close_timestamp;
+        self.markets(market_id).set(&market);
+
+        let mut deadline = self.market_result_deadline(market_id).get();
+        let in_play = self.market_in_play(market_id);
+        if new_close_timestamp >= old_close_timestamp {
+            let shift = new_close_timestamp - old_close_timestamp;
+            deadline += shift;
+            if !in_play.is_empty() {
+                in_play.update(|config| config.end_timestamp += shift);
+            }
+        } else {
+            let shift = old_close_timestamp - new_close_timestamp;
+            deadline -= shift;
+            if !in_play.is_empty() {
+                in_play.update(|config| config.end_timestamp -= shift);
+            }
+        }
+        self.market_result_deadline(market_id).set(deadline);
+
+        self.market_rescheduled_event(market_id, old_close_timestamp, new_close_timestamp);
+    }
+
+    fn require_scheduled_event(&self, event_id: u64) -> SportEvent<Self::Api> {
+        require!(!self.sport_events(event_id).is_empty(), ERR_INVALID_EVENT);
+        let event = self.sport_events(event_id).get();
+        require!(event.status == EventStatus::Scheduled, ERR_EVENT_NOT_SCHEDULED);
+        event
+    }
+
+    #[view(getEvent)]
+    fn get_event(&self, event_id: u64) -> SportEvent<Self::Api> {
+        require!(!self.sport_events(event_id).is_empty(), ERR_INVALID_EVENT);
+        self.sport_events(event_id).get()
+    }
+
+    #[view(getEventMarkets)]
+    fn get_event_markets(&self, event_id: u64) -> MultiValueEncoded<u64> {
+        let mut result = MultiValueEncoded::new();
+        for market_id in self.markets_by_event(event_id).get().iter() {
+            result.push(market_id);
+        }
+        result
+    }
+}
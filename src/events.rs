@@ -1,4 +1,4 @@
-use crate::types::{BetType};
+use crate::types::{AuditCheck, BetLimits, BetType, LimitsOverride, PauseScope, PostponePolicy, ResolutionRule, ResultReport, Role, TimelockAction, TokenConfig};
 
 multiversx_sc::imports!();
 
@@ -77,6 +77,261 @@ pub trait EventsModule {
         #[indexed] current_counter: u64,
     );
 
+    #[event("global_limits_updated")]
+    fn global_limits_updated_event(&self, limits: &BetLimits<Self::Api>);
+
+    #[event("market_limits_updated")]
+    fn market_limits_updated_event(
+        &self,
+        #[indexed] market_id: u64,
+        overrides: &LimitsOverride<Self::Api>,
+    );
+
+    #[event("user_limits_updated")]
+    fn user_limits_updated_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        overrides: &LimitsOverride<Self::Api>,
+    );
+
+    #[event("token_accepted")]
+    fn token_accepted_event(
+        &self,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        config: &TokenConfig<Self::Api>,
+    );
+
+    #[event("token_removed")]
+    fn token_removed_event(&self, #[indexed] token: &EgldOrEsdtTokenIdentifier);
+
+    #[event("audit_discrepancy")]
+    fn audit_discrepancy_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] selection_id: u64,
+        #[indexed] check: AuditCheck,
+        #[indexed] recorded: &BigUint,
+        #[indexed] recomputed: &BigUint,
+    );
+
+    #[event("market_audited")]
+    fn market_audited_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] discrepancies: u32,
+    );
+
+    #[event("role_granted")]
+    fn role_granted_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("role_revoked")]
+    fn role_revoked_event(&self, #[indexed] role: Role, #[indexed] address: &ManagedAddress);
+
+    #[event("market_oracle_assigned")]
+    fn market_oracle_assigned_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] oracle: &ManagedAddress,
+    );
+
+    #[event("result_reported")]
+    fn result_reported_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] oracle: &ManagedAddress,
+        report: &ResultReport<Self::Api>,
+    );
+
+    #[event("event_result_reported")]
+    fn event_result_reported_event(
+        &self,
+        #[indexed] event_id: u64,
+        #[indexed] oracle: &ManagedAddress,
+        #[indexed] reported_markets: u32,
+    );
+
+    #[event("result_disagreement")]
+    fn result_disagreement_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] oracle: &ManagedAddress,
+        #[indexed] conflicting_oracle: &ManagedAddress,
+    );
+
+    #[event("oracle_quorum_updated")]
+    fn oracle_quorum_updated_event(&self, #[indexed] quorum: u32);
+
+    #[event("dispute_config_updated")]
+    fn dispute_config_updated_event(&self, #[indexed] period: u64, #[indexed] bond: &BigUint);
+
+    #[event("result_proposed")]
+    fn result_proposed_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] challenge_deadline: u64,
+        report: &ResultReport<Self::Api>,
+    );
+
+    #[event("result_challenged")]
+    fn result_challenged_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] challenger: &ManagedAddress,
+        #[indexed] bond: &BigUint,
+    );
+
+    #[event("dispute_resolved")]
+    fn dispute_resolved_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] challenger: &ManagedAddress,
+        #[indexed] challenge_upheld: bool,
+        report: &ResultReport<Self::Api>,
+    );
+
+    #[event("bond_slashed")]
+    fn bond_slashed_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] challenger: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("bond_refunded")]
+    fn bond_refunded_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] challenger: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("market_voided")]
+    fn market_voided_event(&self, #[indexed] market_id: u64, #[indexed] timestamp: u64);
+
+    #[event("result_deadline_updated")]
+    fn result_deadline_updated_event(&self, #[indexed] market_id: u64, #[indexed] deadline: u64);
+
+    #[event("keeper_bounty_funded")]
+    fn keeper_bounty_funded_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] funder: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("keeper_rewarded")]
+    fn keeper_rewarded_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] keeper: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+    );
+
+    #[event("market_suspended")]
+    fn market_suspended_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] operator: &ManagedAddress,
+        #[indexed] unmatched_canceled: bool,
+    );
+
+    #[event("market_resumed")]
+    fn market_resumed_event(&self, #[indexed] market_id: u64, #[indexed] operator: &ManagedAddress);
+
+    #[event("in_play_enabled")]
+    fn in_play_enabled_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] end_timestamp: u64,
+        #[indexed] bet_delay: u64,
+    );
+
+    #[event("bet_pending")]
+    fn bet_pending_event(&self, #[indexed] bet_id: u64, #[indexed] activation_time: u64);
+
+    #[event("pending_bet_activated")]
+    fn pending_bet_activated_event(
+        &self,
+        #[indexed] bet_id: u64,
+        #[indexed] matched_amount: &BigUint,
+        #[indexed] unmatched_amount: &BigUint,
+    );
+
+    #[event("pause_updated")]
+    fn pause_updated_event(
+        &self,
+        #[indexed] scope: PauseScope,
+        #[indexed] paused: bool,
+        #[indexed] caller: &ManagedAddress,
+    );
+
+    #[event("treasury_withdrawn")]
+    fn treasury_withdrawn_event(
+        &self,
+        #[indexed] token: &EgldOrEsdtTokenIdentifier,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] manager: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("action_queued")]
+    fn action_queued_event(
+        &self,
+        #[indexed] action_id: u64,
+        #[indexed] eta: u64,
+        action: &TimelockAction<Self::Api>,
+    );
+
+    #[event("action_canceled")]
+    fn action_canceled_event(&self, #[indexed] action_id: u64);
+
+    #[event("action_executed")]
+    fn action_executed_event(&self, #[indexed] action_id: u64);
+
+    #[event("market_type_registered")]
+    fn market_type_registered_event(
+        &self,
+        #[indexed] market_type_id: u64,
+        #[indexed] rule: ResolutionRule,
+        description: &ManagedBuffer,
+    );
+
+    #[event("sport_event_created")]
+    fn sport_event_created_event(
+        &self,
+        #[indexed] event_id: u64,
+        #[indexed] competition_id: u64,
+        #[indexed] kickoff_timestamp: u64,
+    );
+
+    #[event("sport_event_updated")]
+    fn sport_event_updated_event(
+        &self,
+        #[indexed] event_id: u64,
+        #[indexed] kickoff_timestamp: u64,
+    );
+
+    #[event("sport_event_canceled")]
+    fn sport_event_canceled_event(&self, #[indexed] event_id: u64, #[indexed] voided_markets: u32);
+
+    #[event("sport_event_postponed")]
+    fn sport_event_postponed_event(
+        &self,
+        #[indexed] event_id: u64,
+        #[indexed] old_kickoff: u64,
+        #[indexed] new_kickoff: u64,
+        #[indexed] policy: PostponePolicy,
+        #[indexed] voided: bool,
+    );
+
+    #[event("market_rescheduled")]
+    fn market_rescheduled_event(
+        &self,
+        #[indexed] market_id: u64,
+        #[indexed] old_close_timestamp: u64,
+        #[indexed] new_close_timestamp: u64,
+    );
+
     #[event("bet_refunded")]
     fn bet_refunded_event(
         &self,
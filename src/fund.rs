@@ -1,4 +1,4 @@
-use crate::{errors::{ERR_INVALID_MARKET, ERR_MARKET_NOT_CLOSED, ERR_MARKET_NOT_SETTLED}, types::{Bet, BetStatus, BetType, MarketStatus, MarketType, ProcessingProgress, ProcessingStatus}};
+use crate::{errors::{ERR_INCOMPLETE_REPORT, ERR_INSUFFICIENT_TREASURY, ERR_INVALID_MARKET, ERR_MARKET_NOT_CLOSED, ERR_MARKET_NOT_SETTLED, ERR_NOT_TREASURY_MANAGER}, types::{Bet, BetStatus, BetType, MarketStatus, PauseScope, ProcessingProgress, ProcessingStatus, ResolutionRule, ResultReport, Role, Score, SelectionOutcome}};
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
@@ -7,6 +7,10 @@ pub trait FundModule:
     crate::storage::StorageModule
     + crate::events::EventsModule
     + crate::nft::NftModule
+    + crate::tokens::TokensModule
+    + crate::access::AccessModule
+    + crate::pause::PauseModule
+    + crate::timelock::TimelockModule
 {
     fn handle_expired_market(&self, market_id: u64) {
         let mut market = self.markets(market_id).get();
@@ -20,6 +24,30 @@ pub trait FundModule:
         );
     }
 
+    /// Voids a market that will never be settled. Unmatched stakes are refunded
+    /// straight away; matched bets are refunded in full by processBatchBets.
+    fn void_market(&self, market_id: u64) {
+        let mut market = self.markets(market_id).get();
+        if market.market_status == MarketStatus::Open || market.market_status == MarketStatus::Suspended {
+            self.process_unmatched_bets(market_id);
+        }
+        if !self.market_challenge(market_id).is_empty() {
+            self.refund_challenge_bond(market_id);
+        }
+        self.proposed_result(market_id).clear();
+        self.oracle_reports(market_id).clear();
+
+        market.market_status = MarketStatus::Voided;
+        self.markets(market_id).set(&market);
+        self.current_processing_index(market_id).set(0u64);
+
+        if self.market_bet_ids(market_id).is_empty() {
+            self.complete_market_processing(market_id);
+        }
+
+        self.market_voided_event(market_id, self.blockchain().get_block_timestamp());
+    }
+
     fn process_unmatched_bets(&self, market_id: u64) {
         let market = self.markets(market_id).get();
         
@@ -38,16 +66,34 @@ pub trait FundModule:
                 }
             }
 
+            self.selection_back_levels(market_id, selection.id).set(&ManagedVec::new());
+            self.selection_lay_levels(market_id, selection.id).set(&ManagedVec::new());
             self.selection_back_liquidity(market_id, selection.id).set(&BigUint::zero());
             self.selection_lay_liquidity(market_id, selection.id).set(&BigUint::zero());
         }
+
+        while let Some(bet_nonce) = self.pending_bets(market_id).pop_front() {
+            self.process_unmatched_bet(bet_nonce);
+        }
     }
 
     fn process_unmatched_bet(&self, bet_nonce: u64) {
         let mut bet = self.bet_by_id(bet_nonce).get();
         
         if bet.unmatched_amount > BigUint::zero() {
-            let refund_amount = bet.unmatched_amount.clone();
+            let locked_before = self.bet_locked_amount(&bet);
+            let exposure_before = self.bet_exposure(&bet);
+
+            bet.unmatched_amount = BigUint::zero();
+            bet.status = if bet.matched_amount > BigUint::zero() {
+                BetStatus::Matched
+            } else {
+                BetStatus::Canceled
+            };
+
+            let refund_amount = locked_before - self.bet_locked_amount(&bet);
+            let released_exposure = exposure_before - self.bet_exposure(&bet);
+            self.release_bet_funds(&bet, &refund_amount, &released_exposure);
             
             self.send().direct(
                 &bet.bettor,
@@ -56,42 +102,159 @@ pub trait FundModule:
                 &refund_amount,
             );
             
-            let original_matched = bet.matched_amount.clone();
-            bet.unmatched_amount = BigUint::zero();
-            bet.matched_amount = original_matched; 
-            bet.status = if bet.matched_amount > BigUint::zero() {
-                BetStatus::Matched
-            } else {
-                BetStatus::Canceled
-            };
-            
             self.bet_by_id(bet_nonce).set(&bet);
             self.bet_refunded_event(bet_nonce, &bet.bettor, &refund_amount);
         }
     }
 
-    #[only_owner]
-    #[endpoint(setMarketResult)]
-    fn set_market_result(
+    //--------------------------------------------------------------------------------------------//
+    //-------------------------------- Escrow ----------------------------------------------------//
+    //--------------------------------------------------------------------------------------------//
+
+    /// Funds the contract still holds for a bet: the open stake, plus the matching
+    /// share of the liability for Lay bets. Settled, canceled and voided bets hold nothing.
+    fn bet_locked_amount(&self, bet: &Bet<Self::Api>) -> BigUint {
+        if self.is_bet_closed(bet) {
+            return BigUint::zero();
+        }
+
+        let open_stake = &bet.matched_amount + &bet.unmatched_amount;
+        match bet.bet_type {
+            BetType::Back => open_stake,
+            BetType::Lay => {
+                let open_liability = self.liability_share(bet, &open_stake);
+                open_stake + open_liability
+            }
+        }
+    }
+
+    /// Amount the bettor stands to lose: the open stake for Back bets and the
+    /// matching share of the liability for Lay bets.
+    fn bet_exposure(&self, bet: &Bet<Self::Api>) -> BigUint {
+        if self.is_bet_closed(bet) {
+            return BigUint::zero();
+        }
+
+        let open_stake = &bet.matched_amount + &bet.unmatched_amount;
+        match bet.bet_type {
+            BetType::Back => open_stake,
+            BetType::Lay => self.liability_share(bet, &open_stake),
+        }
+    }
+
+    fn is_bet_closed(&self, bet: &Bet<Self::Api>) -> bool {
+        matches!(
+            bet.status,
+            BetStatus::Win | BetStatus::Lost | BetStatus::Canceled | BetStatus::Push
+        )
+    }
+
+    fn liability_share(&self, bet: &Bet<Self::Api>, stake_part: &BigUint) -> BigUint {
+        if bet.stake_amount == BigUint::zero() {
+            return BigUint::zero();
+        }
+        &bet.liability * stake_part / &bet.stake_amount
+    }
+
+    /// Locked funds are tracked per payment token; exposure is tracked in
+    /// normalized units so a single cap covers every token.
+    fn lock_bet_funds(&self, bet: &Bet<Self::Api>) {
+        let locked = self.bet_locked_amount(bet);
+        let exposure = self.to_normalized_amount(&bet.payment_token, &self.bet_exposure(bet));
+
+        self.locked_funds(&bet.bettor, &bet.payment_token).update(|current| *current += &locked);
+        self.user_total_exposure(&bet.bettor).update(|current| *current += &exposure);
+    }
+
+    fn release_bet_funds(
         &self,
-        event_id: u64,
-        market_type_id: u64,
-        score_home: u32,
-        score_away: u32
+        bet: &Bet<Self::Api>,
+        locked: &BigUint,
+        exposure: &BigUint
+    ) {
+        let exposure = self.to_normalized_amount(&bet.payment_token, exposure);
+
+        self.locked_funds(&bet.bettor, &bet.payment_token).update(|current| {
+            *current = if &*current > locked { &*current - locked } else { BigUint::zero() };
+        });
+        self.user_total_exposure(&bet.bettor).update(|current| {
+            *current = if *current > exposure { &*current - &exposure } else { BigUint::zero() };
+        });
+    }
+
+    fn settle_market(
+        &self,
+        market_id: u64,
+        report: ResultReport<Self::Api>
     ) {
-        let market_id = self.get_market_id(event_id, market_type_id);
         let mut market = self.markets(market_id).get();
-        
-        require!(market.market_status == MarketStatus::Closed, "Market not closed");
-        
-        let market_type = MarketType::from_u64(market_type_id);
-        let winning_selection = self.determine_winner(market_type, score_home, score_away);
+        self.market_result(market_id).set(report);
+
+        let winning_selection = self.determine_winner(market_id);
         
         self.winning_selection(market_id).set(winning_selection);
         self.current_processing_index(market_id).set(0u64);
         
         market.market_status = MarketStatus::Settled;
         self.markets(market_id).set(&market);
+        if self.market_bet_ids(market_id).is_empty() {
+            self.complete_market_processing(market_id);
+        }
+
+        self.market_settled_event(market_id, winning_selection, self.market_counter().get());
+    }
+
+    /// Pays the caller one keeper reward out of the market bounty, if any is left.
+    fn pay_keeper_reward(&self, market_id: u64) {
+        let bounty = self.keeper_bounty(market_id).get();
+        let reward = self.keeper_reward_per_action(market_id).get().min(bounty.clone());
+        if reward == BigUint::zero() {
+            return;
+        }
+
+        self.keeper_bounty(market_id).set(&bounty - &reward);
+
+        let keeper = self.blockchain().get_caller();
+        let token = self.markets(market_id).get().settlement_token;
+        self.send().direct(&keeper, &token, 0, &reward);
+        self.keeper_rewarded_event(market_id, &keeper, &reward);
+    }
+
+    /// Moves whatever bounty remains once a market needs no more keeper actions.
+    fn sweep_keeper_bounty(&self, market_id: u64) {
+        let remaining = self.keeper_bounty(market_id).take();
+        if remaining > BigUint::zero() {
+            let token = self.markets(market_id).get().settlement_token;
+            self.treasury_balance(&token).update(|balance| *balance += &remaining);
+        }
+    }
+
+    /// A rejected challenge forfeits its bond to the treasury.
+    fn slash_challenge_bond(&self, market_id: u64) {
+        let challenge = self.market_challenge(market_id).take();
+        self.treasury_balance(&EgldOrEsdtTokenIdentifier::egld())
+            .update(|balance| *balance += &challenge.bond);
+
+        self.bond_slashed_event(market_id, &challenge.challenger, &challenge.bond);
+    }
+
+    #[endpoint(withdrawTreasury)]
+    fn withdraw_treasury(&self, token: EgldOrEsdtTokenIdentifier, amount: BigUint, recipient: ManagedAddress) {
+        let manager = self.require_owner_or_role(Role::TreasuryManager, ERR_NOT_TREASURY_MANAGER);
+        require!(amount <= self.treasury_balance(&token).get(), ERR_INSUFFICIENT_TREASURY);
+
+        self.treasury_balance(&token).update(|balance| *balance -= &amount);
+        self.send().direct(&recipient, &token, 0, &amount);
+
+        self.treasury_withdrawn_event(&token, &recipient, &manager, &amount);
+    }
+
+    /// A successful challenge gets its bond back.
+    fn refund_challenge_bond(&self, market_id: u64) {
+        let challenge = self.market_challenge(market_id).take();
+        self.send().direct_egld(&challenge.challenger, &challenge.bond);
+
+        self.bond_refunded_event(market_id, &challenge.challenger, &challenge.bond);
     }
 
     #[endpoint(processBatchBets)]
@@ -101,48 +264,91 @@ pub trait FundModule:
         batch_size: u64
     ) -> ProcessingStatus {
         let market = self.markets(market_id).get();
+        let voided = market.market_status == MarketStatus::Voided;
         require!(
-            market.market_status == MarketStatus::Settled,
+            market.market_status == MarketStatus::Settled || voided,
             "Market not settled"
         );
+        if !voided {
+            self.require_not_paused(PauseScope::Claims);
+        }
 
-        let winning_selection = self.winning_selection(market_id).get();
+        let bet_ids = self.market_bet_ids(market_id);
+        let total_bets = bet_ids.len() as u64;
+        let mut index = self.current_processing_index(market_id).get();
         let mut processed_count = 0u64;
 
-        for bet_id in self.market_bet_ids(market_id).iter() {
-            if processed_count >= batch_size {
-                return ProcessingStatus::InProgress;
+        while index < total_bets && processed_count < batch_size {
+            index += 1;
+            let bet_id = bet_ids.get_by_index(index as usize);
+
+            if voided {
+                let mut bet = self.bet_by_id(bet_id).get();
+                if !self.is_bet_closed(&bet) {
+                    self.refund_voided_bet(&mut bet);
+                    self.bet_by_id(bet_id).set(&bet);
+                    processed_count += 1;
+                }
+                continue;
+            }
+
+            if self.bet_by_id(bet_id).get().unmatched_amount > BigUint::zero() {
+                self.process_unmatched_bet(bet_id);
             }
 
             let mut bet = self.bet_by_id(bet_id).get();
-            if bet.matched_amount > BigUint::zero() {
-                match bet.bet_type {
-                    BetType::Back => {
-                        if bet.selection.id == winning_selection {
-                            self.process_winning_bet(&mut bet);
-                        } else {
-                            bet.status = BetStatus::Lost;
-                        }
-                    },
-                    BetType::Lay => {
-                        if bet.selection.id != winning_selection {
-                            self.process_winning_bet(&mut bet);
-                        } else {
-                            bet.status = BetStatus::Lost;
-                        }
-                    }
-                }
+            if bet.status == BetStatus::Matched {
+                let outcome = self.selection_outcome(market_id, bet.selection.id);
+                self.settle_bet(&mut bet, outcome);
                 self.bet_by_id(bet_id).set(&bet);
                 processed_count += 1;
             }
         }
-        ProcessingStatus::Completed
+
+        self.current_processing_index(market_id).set(index);
+
+        if processed_count > 0 {
+            self.pay_keeper_reward(market_id);
+        }
+
+        if index < total_bets {
+            ProcessingStatus::InProgress
+        } else {
+            self.complete_market_processing(market_id);
+            ProcessingStatus::Completed
+        }
     }
 
-    fn process_winning_bet(&self, bet: &mut Bet<Self::Api>) {
-        bet.status = BetStatus::Win;
-        let payout = &bet.matched_amount + &bet.potential_profit;
-        
+    /// Once every bet of a resulted market is processed, its leftover bounty
+    /// goes to the treasury and it leaves the active market index.
+    fn complete_market_processing(&self, market_id: u64) {
+        self.sweep_keeper_bounty(market_id);
+        self.active_markets().swap_remove(&market_id);
+    }
+
+    fn settle_bet(&self, bet: &mut Bet<Self::Api>, outcome: SelectionOutcome) {
+        let locked = self.bet_locked_amount(bet);
+        let exposure = self.bet_exposure(bet);
+        let payout = self.calculate_settlement_payout(bet, outcome);
+
+        let bet_won = matches!(
+            (bet.bet_type, outcome),
+            (BetType::Back, SelectionOutcome::Win | SelectionOutcome::HalfWin)
+                | (BetType::Lay, SelectionOutcome::Lose | SelectionOutcome::HalfLose)
+        );
+        bet.status = if outcome == SelectionOutcome::Push {
+            BetStatus::Push
+        } else if bet_won {
+            BetStatus::Win
+        } else {
+            BetStatus::Lost
+        };
+        self.release_bet_funds(bet, &locked, &exposure);
+
+        if payout == BigUint::zero() {
+            return;
+        }
+
         self.send().direct(
             &bet.bettor,
             &bet.payment_token,
@@ -150,41 +356,153 @@ pub trait FundModule:
             &payout
         );
 
-        self.reward_distributed_event(
-            bet.nft_nonce,
-            &bet.bettor,
-            &payout
-        );
+        if bet_won {
+            self.reward_distributed_event(bet.nft_nonce, &bet.bettor, &payout);
+        } else {
+            self.bet_refunded_event(bet.nft_nonce, &bet.bettor, &payout);
+        }
+    }
+
+    fn refund_voided_bet(&self, bet: &mut Bet<Self::Api>) {
+        let locked = self.bet_locked_amount(bet);
+        let exposure = self.bet_exposure(bet);
+
+        bet.status = BetStatus::Canceled;
+        self.release_bet_funds(bet, &locked, &exposure);
+
+        if locked > BigUint::zero() {
+            self.send().direct(&bet.bettor, &bet.payment_token, bet.payment_nonce, &locked);
+            self.bet_refunded_event(bet.nft_nonce, &bet.bettor, &locked);
+        }
+    }
+
+    /// Matched Back stake pays out at the bet odds from the layers' liability.
+    /// A Lay bet always gets its own stake portion back and, when the selection
+    /// loses, also its liability and the backers' matched stake. A push returns
+    /// what each side put in; half outcomes settle half the stake each way.
+    fn calculate_settlement_payout(&self, bet: &Bet<Self::Api>, outcome: SelectionOutcome) -> BigUint {
+        let matched = &bet.matched_amount;
+        let two = BigUint::from(2u32);
+        match (bet.bet_type, outcome) {
+            (BetType::Back, SelectionOutcome::Win) => {
+                let profit = matched * &(&bet.odd - &BigUint::from(100u32)) / &BigUint::from(100u32);
+                matched + &profit
+            },
+            (BetType::Back, SelectionOutcome::HalfWin) => {
+                let profit = matched * &(&bet.odd - &BigUint::from(100u32)) / &BigUint::from(100u32);
+                matched + &(profit / &two)
+            },
+            (BetType::Back, SelectionOutcome::Lose) => BigUint::zero(),
+            (BetType::Back, SelectionOutcome::HalfLose) => matched / &two,
+            (BetType::Back, SelectionOutcome::Push) => matched.clone(),
+            (BetType::Lay, SelectionOutcome::Win) => matched.clone(),
+            (BetType::Lay, SelectionOutcome::HalfWin) => {
+                let matched_liability = self.liability_share(bet, matched);
+                matched + &matched_liability - &(&matched_liability / &two)
+            },
+            (BetType::Lay, SelectionOutcome::Push) => matched + &self.liability_share(bet, matched),
+            (BetType::Lay, SelectionOutcome::HalfLose) => {
+                let matched_liability = self.liability_share(bet, matched);
+                matched * &two + &matched_liability - &(matched / &two)
+            },
+            (BetType::Lay, SelectionOutcome::Lose) => {
+                let matched_liability = self.liability_share(bet, matched);
+                matched * &BigUint::from(2u32) + &matched_liability
+            },
+        }
     }
 
     #[inline]
     fn get_market_id(&self, event_id: u64, market_type_id: u64) -> u64 {
-        let markets = self.markets_by_event(event_id).get();
-        require!(!markets.is_empty(), "Invalid market");
-        markets.get(market_type_id as usize - 1)
+        let market_id = self.event_market_by_type(event_id, market_type_id);
+        require!(!market_id.is_empty(), "Invalid market");
+        market_id.get()
     }
 
-    fn determine_winner(
-        &self,
-        market_type: MarketType,
-        score_home: u32,
-        score_away: u32
-    ) -> u64 {
-        match market_type {
-            MarketType::FullTimeResult => {
-                if score_home > score_away { 1u64 }
-                else if score_home < score_away { 2u64 }
-                else { 3u64 }
+    /// The first selection that won outright, or 0 when none did. Markets with
+    /// several winners are fully described by getSelectionOutcomes.
+    fn determine_winner(&self, market_id: u64) -> u64 {
+        let market = self.markets(market_id).get();
+        market
+            .selections
+            .iter()
+            .map(|selection| selection.id)
+            .find(|selection_id| self.selection_outcome(market_id, *selection_id) == SelectionOutcome::Win)
+            .unwrap_or(0)
+    }
+
+    /// Resolves one selection against the stored final score. Selection ids
+    /// follow the order of the market type's labels. Markets settled before
+    /// result reports existed only recorded their winning selection.
+    fn selection_outcome(&self, market_id: u64, selection_id: u64) -> SelectionOutcome {
+        if self.market_result(market_id).is_empty() {
+            return self.outcome_if(selection_id == self.winning_selection(market_id).get());
+        }
+
+        let market_type_id = self.markets(market_id).get().market_type_id;
+        let rule = self.market_types(market_type_id).get().rule;
+        let report = self.market_result(market_id).get();
+        let (home, away) = (report.score_home as i64, report.score_away as i64);
+
+        let first_selection = match rule {
+            ResolutionRule::MatchResult => {
+                let winner = self.match_result_index(report.score_home, report.score_away) + 1;
+                return self.outcome_if(selection_id == winner);
             },
-            MarketType::TotalGoals => {
-                if score_home + score_away > 2 { 1u64 }
-                else { 2u64 }
+            ResolutionRule::CorrectScore(max_goals) => {
+                let per_side = max_goals as u64 + 1;
+                let winner = if report.score_home <= max_goals && report.score_away <= max_goals {
+                    report.score_home as u64 * per_side + report.score_away as u64 + 1
+                } else {
+                    per_side * per_side + 1
+                };
+                return self.outcome_if(selection_id == winner);
             },
-            MarketType::BothTeamsToScore => {
-                if score_home > 0 && score_away > 0 { 1u64 }
-                else { 2u64 }
-            }
-        }
+            ResolutionRule::HalfTimeResult => {
+                let half_time = self.reported_half_time(&report);
+                let winner = self.match_result_index(half_time.home, half_time.away) + 1;
+                return self.outcome_if(selection_id == winner);
+            },
+            ResolutionRule::HalfTimeFullTime => {
+                let half_time = self.reported_half_time(&report);
+                let winner = self.match_result_index(half_time.home, half_time.away) * 3
+                    + self.match_result_index(report.score_home, report.score_away)
+                    + 1;
+                return self.outcome_if(selection_id == winner);
+            },
+            ResolutionRule::ReportedOutcome(_) => {
+                return self.outcome_if(report.winning_selections.contains(&selection_id));
+            },
+            ResolutionRule::DoubleChance => {
+                let loser = match self.match_result_index(report.score_home, report.score_away) {
+                    0 => 3u64,
+                    1 => 1u64,
+                    _ => 2u64,
+                };
+                return self.outcome_if(selection_id != loser);
+            },
+            ResolutionRule::TotalGoals(line) => SelectionOutcome::from_margin((home + away) * 100 - line as i64),
+            ResolutionRule::BothTeamsToScore => {
+                if home > 0 && away > 0 { SelectionOutcome::Win } else { SelectionOutcome::Lose }
+            },
+            ResolutionRule::AsianHandicap(line) => SelectionOutcome::from_margin((home - away) * 100 + line as i64),
+            ResolutionRule::DrawNoBet => SelectionOutcome::from_margin((home - away) * 100),
+        };
+
+        if selection_id == 1 { first_selection } else { first_selection.opposite() }
+    }
+
+    /// 0 for a home win, 1 for an away win, 2 for a draw.
+    fn match_result_index(&self, home: u32, away: u32) -> u64 {
+        if home > away { 0u64 } else if home < away { 1u64 } else { 2u64 }
+    }
+
+    fn outcome_if(&self, won: bool) -> SelectionOutcome {
+        if won { SelectionOutcome::Win } else { SelectionOutcome::Lose }
+    }
+
+    fn reported_half_time(&self, report: &ResultReport<Self::Api>) -> Score {
+        report.half_time.unwrap_or_else(|| sc_panic!(ERR_INCOMPLETE_REPORT))
     }
 
     // View functions
@@ -208,6 +526,19 @@ pub trait FundModule:
         (winning_selection, market.market_status)
     }
 
+    #[view(getSelectionOutcomes)]
+    fn get_selection_outcomes(&self, market_id: u64) -> MultiValueEncoded<MultiValue2<u64, SelectionOutcome>> {
+        let mut result = MultiValueEncoded::new();
+        if self.market_result(market_id).is_empty() && self.winning_selection(market_id).is_empty() {
+            return result;
+        }
+
+        for selection in self.markets(market_id).get().selections.iter() {
+            result.push((selection.id, self.selection_outcome(market_id, selection.id)).into());
+        }
+        result
+    }
+
     #[view(getBetStatusDetails)]
     fn get_bet_status_details(
         &self,
@@ -224,15 +555,31 @@ pub trait FundModule:
         } else {
             self.current_processing_index(market_id).get()
         };
+        let total_bets = self.market_bet_ids(market_id).len() as u64;
 
         ProcessingProgress {
             market_id,
             processed_bets: current_index,
-            status: if current_index > 0 { 
+            status: if current_index < total_bets { 
                 ProcessingStatus::InProgress 
             } else { 
                 ProcessingStatus::Completed 
             }
         }
     }
+
+    #[view(getTreasuryBalance)]
+    fn get_treasury_balance(&self, token: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.treasury_balance(&token).get()
+    }
+
+    #[view(getUserLockedFunds)]
+    fn get_user_locked_funds(&self, user: ManagedAddress, token: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.locked_funds(&user, &token).get()
+    }
+
+    #[view(getUserExposure)]
+    fn get_user_exposure(&self, user: ManagedAddress) -> BigUint {
+        self.user_total_exposure(&user).get()
+    }
 }
\ No newline at end of file
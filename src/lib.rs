@@ -3,11 +3,21 @@
 pub mod storage;
 pub mod constants;
 pub mod events;
+pub mod event_registry;
 pub mod bet;
 pub mod errors;
 pub mod nft;
 pub mod fund;
 pub mod market;
+pub mod market_types;
+pub mod migration;
+pub mod limits;
+pub mod tokens;
+pub mod audit;
+pub mod access;
+pub mod oracle;
+pub mod pause;
+pub mod timelock;
 pub mod validation;
 pub mod tracker;
 pub mod types;
@@ -18,17 +28,31 @@ multiversx_sc::derive_imports!();
 pub trait Rockstake:
 storage::StorageModule
 + events::EventsModule
++ event_registry::EventRegistryModule
 + nft::NftModule
 + fund::FundModule
 + bet::BetModule
 + market::MarketModule
++ market_types::MarketTypesModule
++ migration::MigrationModule
++ limits::LimitsModule
++ tokens::TokensModule
++ audit::AuditModule
++ access::AccessModule
++ oracle::OracleModule
++ pause::PauseModule
++ timelock::TimelockModule
 + tracker::TrackerModule
 + validation::ValidationModule{
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.register_default_market_types();
+    }
 
     #[init]
     fn init(&self) {
         self.market_counter().set(0);
+        self.storage_version().set(constants::constants::STORAGE_VERSION);
+        self.register_default_market_types();
     }
 }
\ No newline at end of file
@@ -0,0 +1,173 @@
+use crate::constants::constants;
+use crate::errors::{ERR_INVALID_LIMITS, ERR_INVALID_MARKET, ERR_NOT_OPERATOR};
+use crate::types::{BetLimits, LimitsOverride, Role, TimelockAction};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait LimitsModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule +
+    crate::access::AccessModule +
+    crate::timelock::TimelockModule
+{
+    #[only_owner]
+    #[endpoint(setGlobalLimits)]
+    fn set_global_limits(
+        &self,
+        min_stake: BigUint,
+        max_stake: BigUint,
+        max_exposure: BigUint,
+        min_odds: u64,
+        max_odds: u64
+    ) -> u64 {
+        let limits = BetLimits {
+            min_stake,
+            max_stake,
+            max_exposure,
+            min_odds,
+            max_odds,
+        };
+        self.require_valid_limits(&limits);
+
+        self.queue_action(TimelockAction::SetGlobalLimits(limits))
+    }
+
+    /// Market overrides apply at once: a market's betting window is usually
+    /// shorter than the timelock, so a queued change could land after close.
+    /// User overrides outlive any one market and are queued like global
+    /// limits, so the owner can cancel an operator's change before it applies.
+    #[endpoint(setMarketLimits)]
+    fn set_market_limits(&self, market_id: u64, overrides: LimitsOverride<Self::Api>) {
+        self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+        self.require_valid_override(&overrides);
+
+        self.market_limits(market_id).set(&overrides);
+        self.market_limits_updated_event(market_id, &overrides);
+    }
+
+    #[endpoint(clearMarketLimits)]
+    fn clear_market_limits(&self, market_id: u64) {
+        self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        self.market_limits(market_id).clear();
+        self.market_limits_updated_event(market_id, &self.empty_override());
+    }
+
+    #[endpoint(setUserLimits)]
+    fn set_user_limits(&self, user: ManagedAddress, overrides: LimitsOverride<Self::Api>) -> u64 {
+        self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        self.require_valid_override(&overrides);
+
+        self.queue_action(TimelockAction::SetUserLimits(user, overrides))
+    }
+
+    #[endpoint(clearUserLimits)]
+    fn clear_user_limits(&self, user: ManagedAddress) -> u64 {
+        self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        self.queue_action(TimelockAction::SetUserLimits(user, self.empty_override()))
+    }
+
+    /// Limits applied to a bet: global defaults, then the market override,
+    /// then the user override, field by field. Stake and exposure bounds are
+    /// in 18-decimal units and checked against normalized payment amounts.
+    #[view(getEffectiveLimits)]
+    fn get_effective_limits(&self, user: ManagedAddress, market_id: u64) -> BetLimits<Self::Api> {
+        let mut limits = self.get_global_limits();
+
+        if !self.market_limits(market_id).is_empty() {
+            self.apply_override(&mut limits, self.market_limits(market_id).get());
+        }
+        if !self.user_limits(&user).is_empty() {
+            self.apply_override(&mut limits, self.user_limits(&user).get());
+        }
+
+        limits
+    }
+
+    #[view(getGlobalLimits)]
+    fn get_global_limits(&self) -> BetLimits<Self::Api> {
+        if self.global_limits().is_empty() {
+            return self.default_limits();
+        }
+        self.global_limits().get()
+    }
+
+    #[view(getMarketLimits)]
+    fn get_market_limits(&self, market_id: u64) -> LimitsOverride<Self::Api> {
+        if self.market_limits(market_id).is_empty() {
+            return self.empty_override();
+        }
+        self.market_limits(market_id).get()
+    }
+
+    #[view(getUserLimits)]
+    fn get_user_limits(&self, user: ManagedAddress) -> LimitsOverride<Self::Api> {
+        if self.user_limits(&user).is_empty() {
+            return self.empty_override();
+        }
+        self.user_limits(&user).get()
+    }
+
+    fn default_limits(&self) -> BetLimits<Self::Api> {
+        let token_unit = BigUint::from(constants::TOKEN_UNIT);
+        BetLimits {
+            min_stake: &token_unit * &BigUint::from(constants::MIN_STAKE_TOKENS),
+            max_stake: &token_unit * &BigUint::from(constants::MAX_STAKE_TOKENS),
+            max_exposure: &token_unit * &BigUint::from(constants::MAX_EXPOSURE_TOKENS),
+            min_odds: constants::MIN_ODDS as u64,
+            max_odds: constants::MAX_ODDS as u64,
+        }
+    }
+
+    fn empty_override(&self) -> LimitsOverride<Self::Api> {
+        LimitsOverride {
+            min_stake: None,
+            max_stake: None,
+            max_exposure: None,
+            min_odds: None,
+            max_odds: None,
+        }
+    }
+
+    fn apply_override(&self, limits: &mut BetLimits<Self::Api>, overrides: LimitsOverride<Self::Api>) {
+        if let Some(min_stake) = overrides.min_stake {
+            limits.min_stake = min_stake;
+        }
+        if let Some(max_stake) = overrides.max_stake {
+            limits.max_stake = max_stake;
+        }
+        if let Some(max_exposure) = overrides.max_exposure {
+            limits.max_exposure = max_exposure;
+        }
+        if let Some(min_odds) = overrides.min_odds {
+            limits.min_odds = min_odds;
+        }
+        if let Some(max_odds) = overrides.max_odds {
+            limits.max_odds = max_odds;
+        }
+    }
+
+    /// A single maximum Back stake must fit within the exposure limit.
+    fn require_valid_limits(&self, limits: &BetLimits<Self::Api>) {
+        require!(limits.min_stake <= limits.max_stake, ERR_INVALID_LIMITS);
+        require!(limits.max_exposure >= limits.max_stake, ERR_INVALID_LIMITS);
+        require!(
+            limits.min_odds > constants::ODDS_BASE as u64 && limits.min_odds <= limits.max_odds,
+            ERR_INVALID_LIMITS
+        );
+    }
+
+    fn require_valid_override(&self, overrides: &LimitsOverride<Self::Api>) {
+        if let (Some(min_stake), Some(max_stake)) = (&overrides.min_stake, &overrides.max_stake) {
+            require!(min_stake <= max_stake, ERR_INVALID_LIMITS);
+        }
+        if let Some(min_odds) = overrides.min_odds {
+            require!(min_odds > constants::ODDS_BASE as u64, ERR_INVALID_LIMITS);
+        }
+        if let (Some(min_odds), Some(max_odds)) = (overrides.min_odds, overrides.max_odds) {
+            require!(min_odds <= max_odds, ERR_INVALID_LIMITS);
+        }
+    }
+}
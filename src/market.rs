@@ -1,4 +1,11 @@
-use crate::types::{Market, MarketStatus, Selection, Tracker};
+use crate::constants::constants;
+use crate::errors::{
+    ERR_INVALID_IN_PLAY, ERR_INVALID_MARKET, ERR_INVALID_RESULT_DEADLINE, ERR_INVALID_RESULT_TIMEOUT,
+    ERR_MARKET_ALREADY_EXISTS, ERR_MARKET_NOT_CLOSED, ERR_MARKET_NOT_OPEN, ERR_MARKET_NOT_SUSPENDED,
+    ERR_MARKET_NOT_VOIDABLE, ERR_MARKET_TIMESTAMP, ERR_NOT_MARKET_CREATOR, ERR_NOT_OPERATOR,
+    ERR_RESULT_DEADLINE_NOT_REACHED, ERR_RESULT_DEADLINE_PASSED,
+};
+use crate::types::{InPlayConfig, Market, MarketAction, MarketStatus, PauseScope, Role, Selection, Tracker};
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
@@ -9,26 +16,85 @@ pub trait MarketModule:
     crate::fund::FundModule +
     crate::nft::NftModule +
     crate::tracker::TrackerModule +
-    crate::validation::ValidationModule
+    crate::validation::ValidationModule +
+    crate::access::AccessModule +
+    crate::market_types::MarketTypesModule +
+    crate::event_registry::EventRegistryModule +
+    crate::tokens::TokensModule +
+    crate::pause::PauseModule +
+    crate::timelock::TimelockModule
 {
-    #[only_owner]
+    /// Lists a market under a scheduled event. A zero close timestamp closes
+    /// betting at kickoff; an explicit one may not be later than kickoff.
     #[endpoint(createMarket)]
     fn create_market(
         &self,
         event_id: u64,
+        market_type_id: u64,
+        description: ManagedBuffer,
+        close_timestamp: u64,
+        settlement_token: EgldOrEsdtTokenIdentifier
+    ) -> u64 {
+        self.require_owner_or_role(Role::MarketCreator, ERR_NOT_MARKET_CREATOR);
+        self.create_market_for_event(event_id, market_type_id, description, close_timestamp, settlement_token)
+    }
+
+    /// Lists one market per type under an event, named after each market type.
+    /// Fails as a whole if any type is unknown or already listed.
+    #[endpoint(createEventMarkets)]
+    fn create_event_markets(
+        &self,
+        event_id: u64,
+        close_timestamp: u64,
+        settlement_token: EgldOrEsdtTokenIdentifier,
+        market_type_ids: MultiValueEncoded<u64>
+    ) -> MultiValueEncoded<u64> {
+        self.require_owner_or_role(Role::MarketCreator, ERR_NOT_MARKET_CREATOR);
+
+        let mut market_ids = MultiValueEncoded::new();
+        for market_type_id in market_type_ids.into_iter() {
+            let description = self.require_market_type(market_type_id).description;
+            market_ids.push(self.create_market_for_event(
+                event_id,
+                market_type_id,
+                description,
+                close_timestamp,
+                settlement_token.clone()
+            ));
+        }
+        market_ids
+    }
+
+    fn create_market_for_event(
+        &self,
+        event_id: u64,
+        market_type_id: u64,
         description: ManagedBuffer,
-        selection_values: ManagedVec<u64>,
-        close_timestamp: u64
+        close_timestamp: u64,
+        settlement_token: EgldOrEsdtTokenIdentifier
     ) -> u64 {
+        self.require_storage_migrated();
+        let event = self.require_scheduled_event(event_id);
+        require!(!self.has_live_market_of_type(event_id, market_type_id), ERR_MARKET_ALREADY_EXISTS);
+        let close_timestamp = if close_timestamp == 0 {
+            event.kickoff_timestamp
+        } else {
+            require!(close_timestamp <= event.kickoff_timestamp, ERR_MARKET_TIMESTAMP);
+            close_timestamp
+        };
         self.validate_market_creation(close_timestamp);
+        self.require_accepted_token(&settlement_token);
+        let market_type = self.require_market_type(market_type_id);
         
         let market_id = self.get_next_market_id();
-        let selections = self.create_selections(market_id, selection_values);
+        let selections = self.create_selections(market_id, market_type.selection_labels.len());
     
         let market = Market {
             market_id,
             event_id,
+            market_type_id,
             description,
+            settlement_token,
             selections,
             liquidity: BigUint::zero(),
             close_timestamp,
@@ -38,10 +104,13 @@ pub trait MarketModule:
         };
     
         self.markets(market_id).set(&market);
+        self.market_result_deadline(market_id).set(close_timestamp + self.get_result_timeout());
         
         self.markets_by_event(event_id).update(|markets| {
             markets.push(market_id);
         });
+        self.event_market_by_type(event_id, market_type_id).set(market_id);
+        self.active_markets().insert(market_id);
     
         self.market_created_event(market_id, event_id, &self.get_current_market_counter());
     
@@ -53,31 +122,212 @@ pub trait MarketModule:
         let market = self.markets(market_id).get();
         
         require!(
-            market.market_status == MarketStatus::Open,
+            market.market_status == MarketStatus::Open || market.market_status == MarketStatus::Suspended,
             "Market not open"
         );
         
         require!(
-            self.blockchain().get_block_timestamp() >= market.close_timestamp,
+            self.blockchain().get_block_timestamp() >= self.betting_close_timestamp(market_id),
             "Market timestamp not reached"
         );
 
         self.handle_expired_market(market_id);
+        self.pay_keeper_reward(market_id);
+    }
+
+    /// Halts betting on an open market, optionally refunding every unmatched bet.
+    #[endpoint(suspendMarket)]
+    fn suspend_market(&self, market_id: u64, cancel_unmatched: bool) {
+        let operator = self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+        require!(
+            self.markets(market_id).get().market_status == MarketStatus::Open,
+            ERR_MARKET_NOT_OPEN
+        );
+
+        if cancel_unmatched {
+            self.process_unmatched_bets(market_id);
+        }
+
+        let mut market = self.markets(market_id).get();
+        market.market_status = MarketStatus::Suspended;
+        self.markets(market_id).set(&market);
+        self.market_suspended_at(market_id).set(self.blockchain().get_block_timestamp());
+
+        self.market_suspended_event(market_id, &operator, cancel_unmatched);
+    }
+
+    #[endpoint(resumeMarket)]
+    fn resume_market(&self, market_id: u64) {
+        let operator = self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+
+        let mut market = self.markets(market_id).get();
+        require!(market.market_status == MarketStatus::Suspended, ERR_MARKET_NOT_SUSPENDED);
+
+        market.market_status = MarketStatus::Open;
+        self.markets(market_id).set(&market);
+
+        self.market_resumed_event(market_id, &operator);
+    }
+
+    /// Cancels a market before it is settled. Unmatched bets are refunded now
+    /// and matched bets through processBatchBets.
+    #[endpoint(voidMarket)]
+    fn void_market_endpoint(&self, market_id: u64) {
+        self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+
+        let status = self.markets(market_id).get().market_status;
+        require!(
+            status != MarketStatus::Settled && status != MarketStatus::Voided,
+            ERR_MARKET_NOT_VOIDABLE
+        );
+
+        self.void_market(market_id);
+    }
+
+    /// Moves betting close for a market that is still taking bets, for example
+    /// when only this market needs a different cut-off.
+    #[endpoint(rescheduleMarket)]
+    fn reschedule_market(&self, market_id: u64, new_close_timestamp: u64) {
+        self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+
+        let market = self.markets(market_id).get();
+        require!(
+            market.market_status == MarketStatus::Open || market.market_status == MarketStatus::Suspended,
+            ERR_MARKET_NOT_OPEN
+        );
+        require!(
+            new_close_timestamp > self.blockchain().get_block_timestamp(),
+            ERR_MARKET_TIMESTAMP
+        );
+        if !self.sport_events(market.event_id).is_empty() {
+            require!(
+                new_close_timestamp <= self.sport_events(market.event_id).get().kickoff_timestamp,
+                ERR_MARKET_TIMESTAMP
+            );
+        }
+
+        self.move_market_close(market_id, new_close_timestamp);
+    }
+
+    /// Keeps the market open after kickoff until `end_timestamp`. Bets placed
+    /// in that phase wait `bet_delay` seconds before they can be matched.
+    #[endpoint(enableInPlay)]
+    fn enable_in_play(&self, market_id: u64, end_timestamp: u64, bet_delay: u64) {
+        self.require_owner_or_role(Role::Operator, ERR_NOT_OPERATOR);
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+
+        let market = self.markets(market_id).get();
+        require!(market.market_status == MarketStatus::Open, ERR_MARKET_NOT_OPEN);
+        require!(end_timestamp > market.close_timestamp && bet_delay > 0, ERR_INVALID_IN_PLAY);
+
+        self.market_in_play(market_id).set(&InPlayConfig { end_timestamp, bet_delay });
+
+        let min_deadline = end_timestamp + self.get_result_timeout();
+        if self.get_market_result_deadline(market_id) < min_deadline {
+            self.market_result_deadline(market_id).set(min_deadline);
+        }
+
+        self.in_play_enabled_event(market_id, end_timestamp, bet_delay);
+    }
+
+    /// Adds to the bounty paid to whoever closes or settles the market.
+    #[payable("*")]
+    #[endpoint(fundKeeperBounty)]
+    fn fund_keeper_bounty(&self, market_id: u64) {
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+        let (token, nonce, amount) = self.call_value().egld_or_single_esdt().into_tuple();
+        self.validate_market_token(market_id, &token, nonce);
+
+        self.keeper_bounty(market_id).update(|bounty| *bounty += &amount);
+        self.keeper_bounty_funded_event(market_id, &self.blockchain().get_caller(), &amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setKeeperReward)]
+    fn set_keeper_reward(&self, market_id: u64, reward_per_action: BigUint) {
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+        self.keeper_reward_per_action(market_id).set(&reward_per_action);
+    }
+
+    /// Voids a market whose result never arrived. Callable by any participant
+    /// once the result deadline has passed; stakes and liabilities are then
+    /// returned through processBatchBets. A challenged result that was never
+    /// resolved counts as missing, and the challenger's bond is refunded.
+    /// Unavailable while settlement is paused, since oracles can't report then.
+    #[endpoint(refundUnresultedMarket)]
+    fn refund_unresulted_market(&self, market_id: u64) {
+        self.require_not_paused(PauseScope::Settlement);
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+        let market = self.markets(market_id).get();
+
+        require!(
+            market.market_status == MarketStatus::Open
+                || market.market_status == MarketStatus::Suspended
+                || market.market_status == MarketStatus::Closed
+                || (market.market_status == MarketStatus::Proposed && !self.market_challenge(market_id).is_empty()),
+            ERR_MARKET_NOT_CLOSED
+        );
+        require!(
+            self.blockchain().get_block_timestamp() > self.get_market_result_deadline(market_id),
+            ERR_RESULT_DEADLINE_NOT_REACHED
+        );
+
+        self.void_market(market_id);
+    }
+
+    /// Time allowed after close for a result, applied to markets created afterwards.
+    #[only_owner]
+    #[endpoint(setResultTimeout)]
+    fn set_result_timeout(&self, timeout: u64) {
+        require!(timeout > 0 && timeout <= constants::MAX_RESULT_TIMEOUT, ERR_INVALID_RESULT_TIMEOUT);
+        self.result_timeout().set(timeout);
+    }
+
+    /// Extends a market's result deadline while it is still running. The new
+    /// deadline may not exceed the maximum timeout after betting closes.
+    #[only_owner]
+    #[endpoint(setMarketResultDeadline)]
+    fn set_market_result_deadline(&self, market_id: u64, deadline: u64) {
+        require!(!self.markets(market_id).is_empty(), ERR_INVALID_MARKET);
+
+        let current_deadline = self.get_market_result_deadline(market_id);
+        require!(
+            self.blockchain().get_block_timestamp() <= current_deadline,
+            ERR_RESULT_DEADLINE_PASSED
+        );
+        require!(
+            deadline > current_deadline
+                && deadline <= self.betting_close_timestamp(market_id) + constants::MAX_RESULT_TIMEOUT,
+            ERR_INVALID_RESULT_DEADLINE
+        );
+
+        self.market_result_deadline(market_id).set(deadline);
+        self.result_deadline_updated_event(market_id, deadline);
+    }
+
+    /// A voided market no longer blocks its type, so the event can be relisted.
+    fn has_live_market_of_type(&self, event_id: u64, market_type_id: u64) -> bool {
+        let mapped = self.event_market_by_type(event_id, market_type_id);
+        !mapped.is_empty() && self.markets(mapped.get()).get().market_status != MarketStatus::Voided
     }
 
     fn create_selections(
         &self,
         market_id: u64,
-        descriptions: ManagedVec<u64>
+        selection_count: usize
     ) -> ManagedVec<Selection<Self::Api>> {
         let mut selections = ManagedVec::new();
-        for (index, value) in descriptions.iter().enumerate() {
+        for index in 0..selection_count {
             let id = (index + 1) as u64;
             self.init_selection_storage(market_id, id);
             let tracker = self.selection_tracker(market_id, id).get();
             selections.push(Selection {
                 id,
-                value: value,
+                value: id,
                 priority_queue: tracker,
             });
         }
@@ -130,11 +380,79 @@ pub trait MarketModule:
             .unwrap_or_else(|| sc_panic!("Selection not found"))
     }
 
+    #[view(getEventMarketByType)]
+    fn get_event_market_by_type(&self, event_id: u64, market_type_id: u64) -> OptionalValue<u64> {
+        if self.event_market_by_type(event_id, market_type_id).is_empty() {
+            return OptionalValue::None;
+        }
+        OptionalValue::Some(self.event_market_by_type(event_id, market_type_id).get())
+    }
+
     #[view(getMarketStatus)]
     fn get_market_status(&self, market_id: u64) -> MarketStatus {
         self.markets(market_id).get().market_status
     }
 
+    #[view(getMarketSettlementToken)]
+    fn get_market_settlement_token(&self, market_id: u64) -> EgldOrEsdtTokenIdentifier {
+        self.markets(market_id).get().settlement_token
+    }
+
+    #[view(getMarketsAwaitingAction)]
+    fn get_markets_awaiting_action(&self) -> MultiValueEncoded<MultiValue2<u64, MarketAction>> {
+        let mut result = MultiValueEncoded::new();
+        let now = self.blockchain().get_block_timestamp();
+
+        for market_id in self.active_markets().iter() {
+            let market = self.markets(market_id).get();
+            let action = match market.market_status {
+                MarketStatus::Open | MarketStatus::Suspended | MarketStatus::Closed
+                    if now > self.get_market_result_deadline(market_id) => Some(MarketAction::RefundUnresulted),
+                MarketStatus::Open | MarketStatus::Suspended
+                    if now >= self.betting_close_timestamp(market_id) => Some(MarketAction::Close),
+                MarketStatus::Proposed
+                    if !self.market_challenge(market_id).is_empty()
+                        && now > self.get_market_result_deadline(market_id) => Some(MarketAction::RefundUnresulted),
+                MarketStatus::Proposed
+                    if self.market_challenge(market_id).is_empty()
+                        && now >= self.proposed_result(market_id).get().challenge_deadline => Some(MarketAction::FinalizeResult),
+                MarketStatus::Settled if self.has_unprocessed_bets(market_id) => Some(MarketAction::ProcessSettlement),
+                MarketStatus::Voided if self.has_unprocessed_bets(market_id) => Some(MarketAction::ProcessRefunds),
+                _ => None,
+            };
+
+            if let Some(action) = action {
+                result.push((market_id, action).into());
+            }
+        }
+        result
+    }
+
+    fn has_unprocessed_bets(&self, market_id: u64) -> bool {
+        (self.current_processing_index(market_id).get() as usize) < self.market_bet_ids(market_id).len()
+    }
+
+    #[view(getKeeperBounty)]
+    fn get_keeper_bounty(&self, market_id: u64) -> MultiValue2<BigUint, BigUint> {
+        (self.keeper_bounty(market_id).get(), self.keeper_reward_per_action(market_id).get()).into()
+    }
+
+    #[view(getMarketResultDeadline)]
+    fn get_market_result_deadline(&self, market_id: u64) -> u64 {
+        if self.market_result_deadline(market_id).is_empty() {
+            return self.markets(market_id).get().close_timestamp + self.get_result_timeout();
+        }
+        self.market_result_deadline(market_id).get()
+    }
+
+    #[view(getResultTimeout)]
+    fn get_result_timeout(&self) -> u64 {
+        if self.result_timeout().is_empty() {
+            return constants::DEFAULT_RESULT_TIMEOUT;
+        }
+        self.result_timeout().get()
+    }
+
     #[view(getCurrentMarketCounter)]
     fn get_current_market_counter(&self) -> u64 {
         if self.market_counter().is_empty() {
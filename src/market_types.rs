@@ -0,0 +1,108 @@
+use crate::constants::constants;
+use crate::errors::{ERR_INVALID_MARKET_TYPE, ERR_MARKET_TYPE_EXISTS, ERR_UNKNOWN_MARKET_TYPE};
+use crate::types::{MarketTypeConfig, ResolutionRule};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait MarketTypesModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule
+{
+    /// Registers a new kind of market. Labels are listed in selection id order
+    /// and must match the number of outcomes the rule resolves to.
+    #[only_owner]
+    #[endpoint(registerMarketType)]
+    fn register_market_type(
+        &self,
+        market_type_id: u64,
+        description: ManagedBuffer,
+        rule: ResolutionRule,
+        selection_labels: MultiValueEncoded<ManagedBuffer>
+    ) {
+        require!(!self.market_type_ids().contains(&market_type_id), ERR_MARKET_TYPE_EXISTS);
+        self.store_market_type(market_type_id, description, rule, selection_labels.to_vec());
+    }
+
+    fn store_market_type(
+        &self,
+        market_type_id: u64,
+        description: ManagedBuffer,
+        rule: ResolutionRule,
+        selection_labels: ManagedVec<ManagedBuffer>
+    ) {
+        require!(market_type_id > 0, ERR_INVALID_MARKET_TYPE);
+        require!(rule.selection_count() <= constants::MAX_SELECTIONS, ERR_INVALID_MARKET_TYPE);
+        require!(selection_labels.len() == rule.selection_count(), ERR_INVALID_MARKET_TYPE);
+        require!(rule.is_valid(), ERR_INVALID_MARKET_TYPE);
+
+        self.market_type_registered_event(market_type_id, rule, &description);
+        self.market_types(market_type_id).set(&MarketTypeConfig {
+            id: market_type_id,
+            description,
+            selection_labels,
+            rule,
+        });
+        self.market_type_ids().insert(market_type_id);
+    }
+
+    /// The three market kinds that used to be hard-coded keep their ids.
+    fn register_default_market_types(&self) {
+        self.register_default_market_type(
+            constants::FULL_TIME_RESULT_TYPE,
+            "FullTime Result",
+            ResolutionRule::MatchResult,
+            &["Home", "Away", "Draw"]
+        );
+        self.register_default_market_type(
+            constants::TOTAL_GOALS_TYPE,
+            "Total Goals O/U 2.5",
+            ResolutionRule::TotalGoals(250),
+            &["Over", "Under"]
+        );
+        self.register_default_market_type(
+            constants::BOTH_TEAMS_TO_SCORE_TYPE,
+            "Both Teams To Score",
+            ResolutionRule::BothTeamsToScore,
+            &["Yes", "No"]
+        );
+    }
+
+    fn register_default_market_type(
+        &self,
+        market_type_id: u64,
+        description: &str,
+        rule: ResolutionRule,
+        labels: &[&str]
+    ) {
+        if self.market_type_ids().contains(&market_type_id) {
+            return;
+        }
+
+        let mut selection_labels = ManagedVec::new();
+        for label in labels.iter() {
+            selection_labels.push(ManagedBuffer::from(*label));
+        }
+        self.store_market_type(market_type_id, ManagedBuffer::from(description), rule, selection_labels);
+    }
+
+    fn require_market_type(&self, market_type_id: u64) -> MarketTypeConfig<Self::Api> {
+        require!(self.market_type_ids().contains(&market_type_id), ERR_UNKNOWN_MARKET_TYPE);
+        self.market_types(market_type_id).get()
+    }
+
+    #[view(getMarketType)]
+    fn get_market_type(&self, market_type_id: u64) -> MarketTypeConfig<Self::Api> {
+        self.require_market_type(market_type_id)
+    }
+
+    #[view(getMarketTypes)]
+    fn get_market_types(&self) -> MultiValueEncoded<MarketTypeConfig<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for market_type_id in self.market_type_ids().iter() {
+            result.push(self.market_types(market_type_id).get());
+        }
+        result
+    }
+}
@@ -0,0 +1,122 @@
+use crate::constants::constants;
+use crate::errors::{ERR_LEGACY_MARKET_TYPE, ERR_STORAGE_MIGRATED};
+use crate::types::{Market, MarketStatus};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait MigrationModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule +
+    crate::nft::NftModule +
+    crate::tokens::TokensModule +
+    crate::access::AccessModule +
+    crate::pause::PauseModule +
+    crate::market_types::MarketTypesModule +
+    crate::fund::FundModule +
+    crate::timelock::TimelockModule
+{
+    /// Rewrites records stored by a pre-versioned deployment, `max_markets`
+    /// at a time so large books fit in the block gas limit. Markets gain a
+    /// market type and settlement token, and locked funds and exposure are
+    /// rebuilt from open bets under the per-token layout. Returns the number
+    /// of markets still to migrate; betting stays closed until it reaches 0.
+    #[only_owner]
+    #[endpoint(migrateStorage)]
+    fn migrate_storage(&self, max_markets: u64) -> u64 {
+        require!(self.storage_version().get() < constants::STORAGE_VERSION, ERR_STORAGE_MIGRATED);
+
+        let market_count = self.market_counter().get();
+        let cursor = self.migration_cursor().get();
+        let last = core::cmp::min(cursor + max_markets, market_count);
+        for market_id in (cursor + 1)..=last {
+            self.migrate_legacy_market(market_id);
+        }
+
+        if last < market_count {
+            self.migration_cursor().set(last);
+            return market_count - last;
+        }
+
+        self.migration_cursor().clear();
+        self.storage_version().set(constants::STORAGE_VERSION);
+        0
+    }
+
+    /// Markets used to be typed by their position within the event. Markets
+    /// whose position doesn't line up with a registered type are mapped here
+    /// before they are migrated.
+    #[only_owner]
+    #[endpoint(setLegacyMarketType)]
+    fn set_legacy_market_type(&self, market_id: u64, market_type_id: u64) {
+        require!(self.storage_version().get() < constants::STORAGE_VERSION, ERR_STORAGE_MIGRATED);
+        self.require_market_type(market_type_id);
+        self.legacy_market_type(market_id).set(market_type_id);
+    }
+
+    fn migrate_legacy_market(&self, market_id: u64) {
+        if self.legacy_markets(market_id).is_empty() {
+            return;
+        }
+        let legacy = self.legacy_markets(market_id).get();
+
+        let market_type_id = self.resolve_legacy_market_type(market_id, legacy.event_id);
+        let market_type = self.require_market_type(market_type_id);
+        require!(market_type.selection_labels.len() == legacy.selections.len(), ERR_LEGACY_MARKET_TYPE);
+        if self.event_market_by_type(legacy.event_id, market_type_id).is_empty() {
+            self.event_market_by_type(legacy.event_id, market_type_id).set(market_id);
+        }
+
+        let mut settlement_token = EgldOrEsdtTokenIdentifier::egld();
+        for bet_id in self.market_bet_ids(market_id).iter() {
+            let bet = self.bet_by_id(bet_id).get();
+            settlement_token = bet.payment_token.clone();
+
+            // Balances were kept per bettor across every market, so they are
+            // reset the first time a bettor is seen and rebuilt bet by bet.
+            if !self.legacy_balances_reset(&bet.bettor).get() {
+                self.legacy_locked_funds(&bet.bettor).clear();
+                self.user_total_exposure(&bet.bettor).clear();
+                self.legacy_balances_reset(&bet.bettor).set(true);
+            }
+            self.lock_bet_funds(&bet);
+        }
+
+        let processed = self.current_processing_index(market_id).get() >= self.market_bet_ids(market_id).len() as u64;
+        let is_active = legacy.market_status != MarketStatus::Settled || !processed;
+        let legacy_close = legacy.close_timestamp;
+
+        self.markets(market_id).set(Market {
+            market_id,
+            event_id: legacy.event_id,
+            description: legacy.description,
+            selections: legacy.selections,
+            close_timestamp: legacy.close_timestamp,
+            market_status: legacy.market_status,
+            total_matched_amount: legacy.total_matched_amount,
+            liquidity: legacy.liquidity,
+            created_at: legacy.created_at,
+            market_type_id,
+            settlement_token,
+        });
+
+        // Deadlines are fixed per market; nothing older had a configurable timeout.
+        self.market_result_deadline(market_id).set(legacy_close + constants::DEFAULT_RESULT_TIMEOUT);
+        if is_active {
+            self.active_markets().insert(market_id);
+        }
+    }
+
+    fn resolve_legacy_market_type(&self, market_id: u64, event_id: u64) -> u64 {
+        if !self.legacy_market_type(market_id).is_empty() {
+            return self.legacy_market_type(market_id).take();
+        }
+
+        let event_markets = self.markets_by_event(event_id).get();
+        match event_markets.iter().position(|id| id == market_id) {
+            Some(position) => position as u64 + 1,
+            None => sc_panic!(ERR_LEGACY_MARKET_TYPE),
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use crate::errors::{
+    ERR_ALREADY_CHALLENGED, ERR_ALREADY_REPORTED, ERR_CHALLENGE_WINDOW_CLOSED, ERR_CHALLENGE_WINDOW_OPEN,
+    ERR_INCOMPLETE_REPORT, ERR_INVALID_BOND, ERR_INVALID_OUTCOME, ERR_INVALID_QUORUM, ERR_MARKET_NOT_CLOSED,
+    ERR_MARKET_NOT_PROPOSED, ERR_NO_MARKETS_TO_RESULT, ERR_NOT_CHALLENGED,
+};
+use crate::types::{Challenge, MarketStatus, PauseScope, ProposedResult, ResolutionRule, ResultReport, Role, TimelockAction};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait OracleModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule +
+    crate::nft::NftModule +
+    crate::tokens::TokensModule +
+    crate::fund::FundModule +
+    crate::access::AccessModule +
+    crate::pause::PauseModule +
+    crate::timelock::TimelockModule
+{
+    /// Reports a regular-time score only, for markets that need nothing else.
+    #[endpoint(setMarketResult)]
+    fn set_market_result(
+        &self,
+        event_id: u64,
+        market_type_id: u64,
+        score_home: u32,
+        score_away: u32
+    ) {
+        let report = ResultReport {
+            score_home,
+            score_away,
+            half_time: None,
+            extra_time: None,
+            penalties: None,
+            winning_selections: ManagedVec::new(),
+        };
+        self.set_market_report(event_id, market_type_id, report);
+    }
+
+    /// Reports the winning selection ids of an outcome-reported market, in
+    /// ascending order.
+    #[endpoint(setMarketOutcome)]
+    fn set_market_outcome(
+        &self,
+        event_id: u64,
+        market_type_id: u64,
+        winning_selections: MultiValueEncoded<u64>
+    ) {
+        let report = ResultReport {
+            score_home: 0,
+            score_away: 0,
+            half_time: None,
+            extra_time: None,
+            penalties: None,
+            winning_selections: winning_selections.to_vec(),
+        };
+        self.set_market_report(event_id, market_type_id, report);
+    }
+
+    /// Records an oracle's report for a closed market. Once enough oracles agree
+    /// (including the assigned oracle, if the market has one) the result is
+    /// proposed, or settled directly when no dispute period is configured.
+    #[endpoint(setMarketReport)]
+    fn set_market_report(&self, event_id: u64, market_type_id: u64, report: ResultReport<Self::Api>) {
+        self.require_not_paused(PauseScope::Settlement);
+        let market_id = self.get_market_id(event_id, market_type_id);
+        let oracle = self.require_oracle();
+        let market = self.markets(market_id).get();
+
+        require!(market.market_status == MarketStatus::Closed, ERR_MARKET_NOT_CLOSED);
+        self.require_complete_report(market_id, &report);
+
+        self.submit_report(market_id, &oracle, report);
+    }
+
+    /// Applies one score report to every closed market of the event that can be
+    /// resolved from a score and that the caller has not reported on. Markets that
+    /// are still open, already resulted or outcome-reported are skipped.
+    #[endpoint(setEventResult)]
+    fn set_event_result(&self, event_id: u64, report: ResultReport<Self::Api>) -> u32 {
+        self.require_not_paused(PauseScope::Settlement);
+        let oracle = self.require_oracle();
+        require!(report.winning_selections.is_empty(), ERR_INVALID_OUTCOME);
+
+        let mut reported_markets = 0u32;
+        for market_id in self.markets_by_event(event_id).get().iter() {
+            let market = self.markets(market_id).get();
+            if market.market_status != MarketStatus::Closed
+                || self.oracle_reports(market_id).contains_key(&oracle)
+                || !self.is_score_report_complete(market.market_type_id, &report)
+            {
+                continue;
+            }
+
+            self.submit_report(market_id, &oracle, report.clone());
+            reported_markets += 1;
+        }
+
+        require!(reported_markets > 0, ERR_NO_MARKETS_TO_RESULT);
+        self.event_result_reported_event(event_id, &oracle, reported_markets);
+        reported_markets
+    }
+
+    /// Posts a bond in EGLD against a proposed result before its deadline.
+    #[payable("EGLD")]
+    #[endpoint(challengeResult)]
+    fn challenge_result(&self, market_id: u64) {
+        let market = self.markets(market_id).get();
+        require!(market.market_status == MarketStatus::Proposed, ERR_MARKET_NOT_PROPOSED);
+        require!(self.market_challenge(market_id).is_empty(), ERR_ALREADY_CHALLENGED);
+        require!(
+            self.blockchain().get_block_timestamp() < self.proposed_result(market_id).get().challenge_deadline,
+            ERR_CHALLENGE_WINDOW_CLOSED
+        );
+
+        let bond = self.call_value().egld_value().clone_value();
+        require!(bond == self.proposed_result(market_id).get().challenge_bond, ERR_INVALID_BOND);
+
+        let challenger = self.blockchain().get_caller();
+        self.market_challenge(market_id).set(&Challenge {
+            challenger: challenger.clone(),
+            bond: bond.clone(),
+            created_at: self.blockchain().get_block_timestamp(),
+        });
+
+        self.result_challenged_event(market_id, &challenger, &bond);
+    }
+
+    /// Confirms an unchallenged result once its challenge window has passed.
+    #[endpoint(finalizeResult)]
+    fn finalize_result(&self, market_id: u64) {
+        self.require_not_paused(PauseScope::Settlement);
+        let market = self.markets(market_id).get();
+        require!(market.market_status == MarketStatus::Proposed, ERR_MARKET_NOT_PROPOSED);
+        require!(self.market_challenge(market_id).is_empty(), ERR_ALREADY_CHALLENGED);
+
+        let proposed = self.proposed_result(market_id).get();
+        require!(
+            self.blockchain().get_block_timestamp() >= proposed.challenge_deadline,
+            ERR_CHALLENGE_WINDOW_OPEN
+        );
+
+        self.proposed_result(market_id).clear();
+        self.settle_market(market_id, proposed.report);
+        self.pay_keeper_reward(market_id);
+    }
+
+    /// Settles a challenged market with the arbiter's report. Confirming the
+    /// proposed report slashes the bond; correcting it refunds the challenger.
+    #[only_owner]
+    #[endpoint(resolveDispute)]
+    fn resolve_dispute(&self, market_id: u64, report: ResultReport<Self::Api>) {
+        self.require_not_paused(PauseScope::Settlement);
+        let market = self.markets(market_id).get();
+        require!(market.market_status == MarketStatus::Proposed, ERR_MARKET_NOT_PROPOSED);
+        require!(!self.market_challenge(market_id).is_empty(), ERR_NOT_CHALLENGED);
+        self.require_complete_report(market_id, &report);
+
+        let proposed = self.proposed_result(market_id).take();
+        let challenger = self.market_challenge(market_id).get().challenger;
+        let challenge_upheld = proposed.report != report;
+
+        if challenge_upheld {
+            self.refund_challenge_bond(market_id);
+        } else {
+            self.slash_challenge_bond(market_id);
+        }
+
+        self.dispute_resolved_event(market_id, &challenger, challenge_upheld, &report);
+        self.settle_market(market_id, report);
+    }
+
+    /// A zero period settles results as soon as the oracles agree. Results
+    /// already proposed keep the bond in force when they were proposed.
+    #[only_owner]
+    #[endpoint(setDisputeConfig)]
+    fn set_dispute_config(&self, period: u64, bond: BigUint) -> u64 {
+        require!(bond > BigUint::zero(), ERR_INVALID_BOND);
+        self.queue_action(TimelockAction::SetDisputeConfig(period, bond))
+    }
+
+    fn propose_result(&self, market_id: u64, report: ResultReport<Self::Api>) {
+        let period = self.dispute_period().get();
+        if period == 0 {
+            self.settle_market(market_id, report);
+            return;
+        }
+
+        let proposed_at = self.blockchain().get_block_timestamp();
+        let challenge_deadline = proposed_at + period;
+        self.result_proposed_event(market_id, challenge_deadline, &report);
+        self.proposed_result(market_id).set(&ProposedResult {
+            report,
+            proposed_at,
+            challenge_deadline,
+            challenge_bond: self.dispute_bond().get(),
+        });
+
+        let mut market = self.markets(market_id).get();
+        market.market_status = MarketStatus::Proposed;
+        self.markets(market_id).set(&market);
+    }
+
+    /// Checked against the number of oracles when the queued change executes.
+    #[only_owner]
+    #[endpoint(setOracleQuorum)]
+    fn set_oracle_quorum(&self, quorum: u32) -> u64 {
+        require!(quorum > 0, ERR_INVALID_QUORUM);
+        self.queue_action(TimelockAction::SetOracleQuorum(quorum))
+    }
+
+    fn submit_report(&self, market_id: u64, oracle: &ManagedAddress, report: ResultReport<Self::Api>) {
+        let agreeing_reports = self.record_report(market_id, oracle, &report);
+        self.result_reported_event(market_id, oracle, &report);
+
+        if agreeing_reports >= self.get_oracle_quorum()
+            && self.has_assigned_oracle_agreement(market_id, &report)
+        {
+            self.oracle_reports(market_id).clear();
+            self.propose_result(market_id, report);
+        }
+    }
+
+    /// Stores the report and returns how many current oracles agree with it,
+    /// flagging every registered oracle that reported something else.
+    fn record_report(
+        &self,
+        market_id: u64,
+        oracle: &ManagedAddress,
+        report: &ResultReport<Self::Api>
+    ) -> u32 {
+        let mut reports = self.oracle_reports(market_id);
+        require!(!reports.contains_key(oracle), ERR_ALREADY_REPORTED);
+        reports.insert(oracle.clone(), report.clone());
+
+        let mut agreeing_reports = 0u32;
+        for (reporter, other_report) in reports.iter() {
+            if !self.has_role(Role::Oracle, &reporter) {
+                continue;
+            }
+
+            if &other_report == report {
+                agreeing_reports += 1;
+            } else {
+                self.result_disagreement_event(market_id, oracle, &reporter);
+            }
+        }
+        agreeing_reports
+    }
+
+    fn require_complete_report(&self, market_id: u64, report: &ResultReport<Self::Api>) {
+        let market_type_id = self.markets(market_id).get().market_type_id;
+        let rule = self.market_types(market_type_id).get().rule;
+        if rule.requires_half_time() {
+            require!(report.half_time.is_some(), ERR_INCOMPLETE_REPORT);
+        }
+
+        match rule {
+            ResolutionRule::ReportedOutcome(outcomes) => {
+                require!(!report.winning_selections.is_empty(), ERR_INVALID_OUTCOME);
+
+                let mut previous = 0u64;
+                for selection_id in report.winning_selections.iter() {
+                    require!(
+                        selection_id > previous && selection_id <= outcomes as u64,
+                        ERR_INVALID_OUTCOME
+                    );
+                    previous = selection_id;
+                }
+            },
+            // Score-resolved markets must not carry winners, or an outcome
+            // report would settle them as a 0-0 scoreline.
+            _ => require!(report.winning_selections.is_empty(), ERR_INVALID_OUTCOME),
+        }
+    }
+
+    fn is_score_report_complete(&self, market_type_id: u64, report: &ResultReport<Self::Api>) -> bool {
+        match self.market_types(market_type_id).get().rule {
+            ResolutionRule::ReportedOutcome(_) => false,
+            rule => !rule.requires_half_time() || report.half_time.is_some(),
+        }
+    }
+
+    /// An assigned oracle adds a required signature on top of the quorum; it
+    /// never replaces it.
+    fn has_assigned_oracle_agreement(&self, market_id: u64, report: &ResultReport<Self::Api>) -> bool {
+        if self.market_oracle(market_id).is_empty() {
+            return true;
+        }
+
+        let assigned = self.market_oracle(market_id).get();
+        if !self.has_role(Role::Oracle, &assigned) {
+            return false;
+        }
+        match self.oracle_reports(market_id).get(&assigned) {
+            Some(assigned_report) => &assigned_report == report,
+            None => false,
+        }
+    }
+
+    #[view(getProposedResult)]
+    fn get_proposed_result(&self, market_id: u64) -> OptionalValue<ProposedResult<Self::Api>> {
+        if self.proposed_result(market_id).is_empty() {
+            return OptionalValue::None;
+        }
+        OptionalValue::Some(self.proposed_result(market_id).get())
+    }
+
+    #[view(getMarketChallenge)]
+    fn get_market_challenge(&self, market_id: u64) -> OptionalValue<Challenge<Self::Api>> {
+        if self.market_challenge(market_id).is_empty() {
+            return OptionalValue::None;
+        }
+        OptionalValue::Some(self.market_challenge(market_id).get())
+    }
+
+    #[view(getDisputeConfig)]
+    fn get_dispute_config(&self) -> MultiValue2<u64, BigUint> {
+        (self.dispute_period().get(), self.dispute_bond().get()).into()
+    }
+
+    #[view(getPendingReports)]
+    fn get_pending_reports(
+        &self,
+        market_id: u64
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, ResultReport<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for (oracle, report) in self.oracle_reports(market_id).iter() {
+            result.push((oracle, report).into());
+        }
+        result
+    }
+}
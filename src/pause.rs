@@ -0,0 +1,67 @@
+use crate::constants::constants;
+use crate::errors::{
+    ERR_BETTING_PAUSED, ERR_CLAIMS_PAUSED, ERR_NOT_GUARDIAN, ERR_SETTLEMENT_PAUSED, ERR_STORAGE_NOT_MIGRATED,
+};
+use crate::types::{PauseScope, Role};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait PauseModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule +
+    crate::access::AccessModule +
+    crate::timelock::TimelockModule
+{
+    /// Guardians can halt a scope on their own; lifting a pause is left to
+    /// the owner. Cancelling unmatched bets and refunds stay available.
+    #[endpoint(pause)]
+    fn pause(&self, scope: PauseScope) {
+        let caller = self.require_owner_or_role(Role::Guardian, ERR_NOT_GUARDIAN);
+        self.set_paused(scope, true, &caller);
+    }
+
+    #[endpoint(pauseAll)]
+    fn pause_all(&self) {
+        let caller = self.require_owner_or_role(Role::Guardian, ERR_NOT_GUARDIAN);
+        for scope in [PauseScope::Betting, PauseScope::Settlement, PauseScope::Claims] {
+            self.set_paused(scope, true, &caller);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(unpause)]
+    fn unpause(&self, scope: PauseScope) {
+        let caller = self.blockchain().get_caller();
+        self.set_paused(scope, false, &caller);
+    }
+
+    fn set_paused(&self, scope: PauseScope, paused: bool, caller: &ManagedAddress) {
+        self.paused(&scope).set(paused);
+        self.pause_updated_event(scope, paused, caller);
+    }
+
+    fn require_not_paused(&self, scope: PauseScope) {
+        if !self.paused(&scope).get() {
+            return;
+        }
+
+        match scope {
+            PauseScope::Betting => sc_panic!(ERR_BETTING_PAUSED),
+            PauseScope::Settlement => sc_panic!(ERR_SETTLEMENT_PAUSED),
+            PauseScope::Claims => sc_panic!(ERR_CLAIMS_PAUSED),
+        }
+    }
+
+    /// An upgraded deployment keeps betting closed until `migrateStorage` has
+    /// rewritten every legacy market.
+    fn require_storage_migrated(&self) {
+        require!(self.storage_version().get() >= constants::STORAGE_VERSION, ERR_STORAGE_NOT_MIGRATED);
+    }
+
+    #[view(isPaused)]
+    fn is_paused(&self, scope: PauseScope) -> bool {
+        self.paused(&scope).get()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::types::{Bet, Market, PriceLevel, Tracker};
+use crate::types::{Bet, BetLimits, Challenge, InPlayConfig, LegacyMarket, LimitsOverride, Market, MarketTypeConfig, PauseScope, PriceLevel, ProposedResult, QueuedAction, ResultReport, Role, SportEvent, TokenConfig, Tracker};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -67,19 +67,44 @@ pub trait StorageModule {
     #[storage_mapper("markets")]
     fn markets(&self, market_id: u64) -> SingleValueMapper<Market<Self::Api>>;
 
+    #[storage_mapper("markets")]
+    fn legacy_markets(&self, market_id: u64) -> SingleValueMapper<LegacyMarket<Self::Api>>;
+
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("migrationCursor")]
+    fn migration_cursor(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("legacyMarketType")]
+    fn legacy_market_type(&self, market_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("legacyBalancesReset")]
+    fn legacy_balances_reset(&self, address: &ManagedAddress) -> SingleValueMapper<bool>;
+
     #[storage_mapper("total_matched_amount")]
     fn total_matched_amount(&self, market_id: u64, selection_id: u64)
         -> SingleValueMapper<BigUint<Self::Api>>;
 
     #[storage_mapper("locked_funds")]
-    fn locked_funds(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint<Self::Api>>;
+    fn locked_funds(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier
+    ) -> SingleValueMapper<BigUint<Self::Api>>;
     
+    #[storage_mapper("locked_funds")]
+    fn legacy_locked_funds(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint<Self::Api>>;
+
     #[storage_mapper("user_exposure")]
     fn user_total_exposure(
         &self,
         user: &ManagedAddress<Self::Api>
     ) -> SingleValueMapper<BigUint>;
 
+    #[storage_mapper("activeMarkets")]
+    fn active_markets(&self) -> UnorderedSetMapper<u64>;
+
     #[storage_mapper("marketsByEvent")]
     fn markets_by_event(&self, event_id: u64) -> SingleValueMapper<ManagedVec<u64>>;
 
@@ -89,5 +114,102 @@ pub trait StorageModule {
     #[storage_mapper("currentProcessingIndex")]
     fn current_processing_index(&self, market_id: u64) -> SingleValueMapper<u64>;
 
-}
+    #[storage_mapper("globalLimits")]
+    fn global_limits(&self) -> SingleValueMapper<BetLimits<Self::Api>>;
+
+    #[storage_mapper("marketLimits")]
+    fn market_limits(&self, market_id: u64) -> SingleValueMapper<LimitsOverride<Self::Api>>;
+
+    #[storage_mapper("userLimits")]
+    fn user_limits(&self, user: &ManagedAddress) -> SingleValueMapper<LimitsOverride<Self::Api>>;
+
+    #[storage_mapper("acceptedTokens")]
+    fn accepted_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[storage_mapper("tokenConfig")]
+    fn token_config(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<TokenConfig<Self::Api>>;
+
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: &Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("marketOracle")]
+    fn market_oracle(&self, market_id: u64) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("oracleAssignedMarkets")]
+    fn oracle_assigned_markets(&self, oracle: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("oracleQuorum")]
+    fn oracle_quorum(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("oracleReports")]
+    fn oracle_reports(&self, market_id: u64) -> MapMapper<ManagedAddress, ResultReport<Self::Api>>;
+
+    #[storage_mapper("disputePeriod")]
+    fn dispute_period(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("disputeBond")]
+    fn dispute_bond(&self) -> SingleValueMapper<BigUint>;
 
+    #[storage_mapper("proposedResult")]
+    fn proposed_result(&self, market_id: u64) -> SingleValueMapper<ProposedResult<Self::Api>>;
+
+    #[storage_mapper("marketChallenge")]
+    fn market_challenge(&self, market_id: u64) -> SingleValueMapper<Challenge<Self::Api>>;
+
+    #[storage_mapper("treasuryBalance")]
+    fn treasury_balance(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("resultTimeout")]
+    fn result_timeout(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("marketResultDeadline")]
+    fn market_result_deadline(&self, market_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("keeperBounty")]
+    fn keeper_bounty(&self, market_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("keeperRewardPerAction")]
+    fn keeper_reward_per_action(&self, market_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("marketInPlay")]
+    fn market_in_play(&self, market_id: u64) -> SingleValueMapper<InPlayConfig>;
+
+    #[storage_mapper("marketSuspendedAt")]
+    fn market_suspended_at(&self, market_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("pendingBets")]
+    fn pending_bets(&self, market_id: u64) -> QueueMapper<u64>;
+
+    #[storage_mapper("betActivationTime")]
+    fn bet_activation_time(&self, bet_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("paused")]
+    fn paused(&self, scope: &PauseScope) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("timelockDelay")]
+    fn timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("lastQueuedActionId")]
+    fn last_queued_action_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("queuedActions")]
+    fn queued_actions(&self) -> MapMapper<u64, QueuedAction<Self::Api>>;
+
+    #[storage_mapper("marketTypeIds")]
+    fn market_type_ids(&self) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("marketTypes")]
+    fn market_types(&self, market_type_id: u64) -> SingleValueMapper<MarketTypeConfig<Self::Api>>;
+
+    #[storage_mapper("marketResult")]
+    fn market_result(&self, market_id: u64) -> SingleValueMapper<ResultReport<Self::Api>>;
+
+    #[storage_mapper("eventCounter")]
+    fn event_counter(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("sportEvents")]
+    fn sport_events(&self, event_id: u64) -> SingleValueMapper<SportEvent<Self::Api>>;
+
+    #[storage_mapper("eventMarketByType")]
+    fn event_market_by_type(&self, event_id: u64, market_type_id: u64) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,147 @@
+use crate::errors::{
+    ERR_ACTION_NOT_QUEUED, ERR_INVALID_QUORUM, ERR_INVALID_TIMELOCK_DELAY, ERR_INVALID_TOKEN_CONFIG,
+    ERR_ROLE_ALREADY_GRANTED, ERR_ROLE_NOT_GRANTED, ERR_TIMELOCK_NOT_EXPIRED,
+};
+use crate::types::{QueuedAction, Role, TimelockAction};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait TimelockModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule
+{
+    /// Queues a new timelock delay. The current delay still applies to this change.
+    #[only_owner]
+    #[endpoint(setTimelockDelay)]
+    fn set_timelock_delay(&self, delay: u64) -> u64 {
+        require!(delay > 0, ERR_INVALID_TIMELOCK_DELAY);
+        self.queue_action(TimelockAction::SetTimelockDelay(delay))
+    }
+
+    #[only_owner]
+    #[endpoint(cancelAction)]
+    fn cancel_action(&self, action_id: u64) {
+        require!(self.queued_actions().remove(&action_id).is_some(), ERR_ACTION_NOT_QUEUED);
+        self.action_canceled_event(action_id);
+    }
+
+    /// Applies a queued change once its eta has passed. Anyone can execute it;
+    /// the owner already approved it when queueing.
+    #[endpoint(executeAction)]
+    fn execute_action(&self, action_id: u64) {
+        let queued = self
+            .queued_actions()
+            .get(&action_id)
+            .unwrap_or_else(|| sc_panic!(ERR_ACTION_NOT_QUEUED));
+        require!(
+            self.blockchain().get_block_timestamp() >= queued.eta,
+            ERR_TIMELOCK_NOT_EXPIRED
+        );
+
+        self.queued_actions().remove(&action_id);
+        self.apply_action(queued.action);
+        self.action_executed_event(action_id);
+    }
+
+    fn queue_action(&self, action: TimelockAction<Self::Api>) -> u64 {
+        let action_id = self.last_queued_action_id().get() + 1;
+        self.last_queued_action_id().set(action_id);
+
+        let queued_at = self.blockchain().get_block_timestamp();
+        let eta = queued_at + self.get_timelock_delay();
+        self.action_queued_event(action_id, eta, &action);
+        self.queued_actions().insert(action_id, QueuedAction { action, queued_at, eta });
+
+        action_id
+    }
+
+    fn apply_action(&self, action: TimelockAction<Self::Api>) {
+        match action {
+            TimelockAction::GrantRole(role, address) => {
+                require!(self.role_members(&role).insert(address.clone()), ERR_ROLE_ALREADY_GRANTED);
+                self.role_granted_event(role, &address);
+            },
+            TimelockAction::SetGlobalLimits(limits) => {
+                self.global_limits().set(&limits);
+                self.global_limits_updated_event(&limits);
+            },
+            TimelockAction::SetOracleQuorum(quorum) => {
+                require!(
+                    quorum as usize <= self.role_members(&Role::Oracle).len(),
+                    ERR_INVALID_QUORUM
+                );
+                self.oracle_quorum().set(quorum);
+                self.oracle_quorum_updated_event(quorum);
+            },
+            TimelockAction::SetDisputeConfig(period, bond) => {
+                self.dispute_period().set(period);
+                self.dispute_bond().set(&bond);
+                self.dispute_config_updated_event(period, &bond);
+            },
+            TimelockAction::SetTimelockDelay(delay) => {
+                self.timelock_delay().set(delay);
+            },
+            TimelockAction::SetUserLimits(user, overrides) => {
+                if overrides.is_empty() {
+                    self.user_limits(&user).clear();
+                } else {
+                    self.user_limits(&user).set(&overrides);
+                }
+                self.user_limits_updated_event(&user, &overrides);
+            },
+            TimelockAction::AddAcceptedToken(token, config) => {
+                if !self.token_config(&token).is_empty() {
+                    require!(
+                        self.token_config(&token).get().decimals == config.decimals,
+                        ERR_INVALID_TOKEN_CONFIG
+                    );
+                }
+                self.token_config(&token).set(&config);
+                self.accepted_tokens().insert(token.clone());
+                self.token_accepted_event(&token, &config);
+            },
+            TimelockAction::AssignMarketOracle(market_id, oracle) => {
+                require!(self.role_members(&Role::Oracle).contains(&oracle), ERR_ROLE_NOT_GRANTED);
+                if !self.market_oracle(market_id).is_empty() {
+                    let previous = self.market_oracle(market_id).get();
+                    self.oracle_assigned_markets(&previous).swap_remove(&market_id);
+                }
+                self.market_oracle(market_id).set(&oracle);
+                self.oracle_assigned_markets(&oracle).insert(market_id);
+                self.market_oracle_assigned_event(market_id, &oracle);
+            },
+            TimelockAction::UnassignMarketOracle(market_id) => {
+                if !self.market_oracle(market_id).is_empty() {
+                    let oracle = self.market_oracle(market_id).take();
+                    self.oracle_assigned_markets(&oracle).swap_remove(&market_id);
+                }
+            },
+        }
+    }
+
+    /// Zero until the first setTimelockDelay, so a fresh deployment can be
+    /// configured before it holds any funds.
+    #[view(getTimelockDelay)]
+    fn get_timelock_delay(&self) -> u64 {
+        self.timelock_delay().get()
+    }
+
+    #[view(getQueuedActions)]
+    fn get_queued_actions(&self) -> MultiValueEncoded<MultiValue2<u64, QueuedAction<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for (action_id, queued) in self.queued_actions().iter() {
+            result.push((action_id, queued).into());
+        }
+        result
+    }
+
+    #[view(getQueuedAction)]
+    fn get_queued_action(&self, action_id: u64) -> OptionalValue<QueuedAction<Self::Api>> {
+        match self.queued_actions().get(&action_id) {
+            Some(queued) => OptionalValue::Some(queued),
+            None => OptionalValue::None,
+        }
+    }
+}
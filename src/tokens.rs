@@ -0,0 +1,83 @@
+use crate::constants::constants;
+use crate::errors::{ERR_INVALID_TOKEN_CONFIG, ERR_TOKEN_NOT_ACCEPTED};
+use crate::types::{TimelockAction, TokenConfig};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[multiversx_sc::module]
+pub trait TokensModule:
+    crate::storage::StorageModule +
+    crate::events::EventsModule +
+    crate::timelock::TimelockModule
+{
+    /// Queues adding a token to the whitelist or updating its stake range. Stake
+    /// bounds are in the token's own units; decimals cannot change once a token
+    /// is known.
+    #[only_owner]
+    #[endpoint(addAcceptedToken)]
+    fn add_accepted_token(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        decimals: u32,
+        min_stake: BigUint,
+        max_stake: BigUint
+    ) -> u64 {
+        require!(token.is_valid(), ERR_INVALID_TOKEN_CONFIG);
+        require!(decimals <= constants::NORMALIZED_DECIMALS, ERR_INVALID_TOKEN_CONFIG);
+        require!(min_stake > BigUint::zero() && min_stake <= max_stake, ERR_INVALID_TOKEN_CONFIG);
+        if !self.token_config(&token).is_empty() {
+            require!(
+                self.token_config(&token).get().decimals == decimals,
+                ERR_INVALID_TOKEN_CONFIG
+            );
+        }
+
+        let config = TokenConfig {
+            decimals,
+            min_stake,
+            max_stake,
+        };
+        self.queue_action(TimelockAction::AddAcceptedToken(token, config))
+    }
+
+    /// Stops accepting new bets in the token. The config is kept so open bets
+    /// can still be released and settled.
+    #[only_owner]
+    #[endpoint(removeAcceptedToken)]
+    fn remove_accepted_token(&self, token: EgldOrEsdtTokenIdentifier) {
+        require!(self.accepted_tokens().swap_remove(&token), ERR_TOKEN_NOT_ACCEPTED);
+        self.token_removed_event(&token);
+    }
+
+    fn require_accepted_token(&self, token: &EgldOrEsdtTokenIdentifier) -> TokenConfig<Self::Api> {
+        require!(self.accepted_tokens().contains(token), ERR_TOKEN_NOT_ACCEPTED);
+        self.token_config(token).get()
+    }
+
+    /// Scales an amount to 18 decimals so limits apply evenly across tokens.
+    fn to_normalized_amount(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) -> BigUint {
+        let decimals = if self.token_config(token).is_empty() {
+            constants::NORMALIZED_DECIMALS
+        } else {
+            self.token_config(token).get().decimals
+        };
+
+        amount * &BigUint::from(10u64).pow(constants::NORMALIZED_DECIMALS - decimals)
+    }
+
+    #[view(getAcceptedTokens)]
+    fn get_accepted_tokens(&self) -> MultiValueEncoded<EgldOrEsdtTokenIdentifier> {
+        let mut result = MultiValueEncoded::new();
+        for token in self.accepted_tokens().iter() {
+            result.push(token);
+        }
+        result
+    }
+
+    #[view(getTokenConfig)]
+    fn get_token_config(&self, token: EgldOrEsdtTokenIdentifier) -> TokenConfig<Self::Api> {
+        require!(!self.token_config(&token).is_empty(), ERR_TOKEN_NOT_ACCEPTED);
+        self.token_config(&token).get()
+    }
+}
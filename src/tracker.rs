@@ -20,71 +20,53 @@ pub trait TrackerModule:
         let mut i = 0;
         while i < levels.len() && remaining > BigUint::zero() {
             let mut level = levels.get(i);
-            
-            if level.odds == bet.odd {
-            
-                let match_amount = match bet.bet_type {
-                    BetType::Back => {
-                        remaining.clone().min(level.total_stake.clone())
-                    },
-                    BetType::Lay => {
-                        bet.liability.clone().min(level.total_stake.clone())
-                    }
-                };
-                
-                if match_amount > BigUint::zero() {
-                    matched_amount += &match_amount;
-                    remaining -= &match_amount;
-                    level.total_stake -= &match_amount;
-    
-                    // Update matched bets
-                    let mut updated_nonces = ManagedVec::new();
-                    for nonce in level.bet_nonces.iter() {
-                        let mut matched_bet = self.bet_by_id(nonce).get();
-                        if matched_bet.unmatched_amount > BigUint::zero() {
-                            let match_this_bet = matched_bet.unmatched_amount.clone().min(match_amount.clone());
-                            
-                            if match_this_bet > BigUint::zero() {
-                                matched_bet.matched_amount += &match_this_bet;
-                                matched_bet.unmatched_amount -= &match_this_bet;
-                                
-                                matched_bet.status = if matched_bet.unmatched_amount == BigUint::zero() {
-                                    BetStatus::Matched
-                                } else {
-                                    BetStatus::PartiallyMatched
-                                };
-                                
-                                self.bet_by_id(nonce).set(&matched_bet);
-    
-                                if matched_bet.unmatched_amount > BigUint::zero() {
-                                    updated_nonces.push(nonce);
-                                }
-                            }
-                        }
-                    }
-    
-                    if !updated_nonces.is_empty() {
-                        level.bet_nonces = updated_nonces;
-                        let _ = levels.set(i, level);
-                        i += 1;
+
+            if level.odds != bet.odd {
+                i += 1;
+                continue;
+            }
+
+            // Both sides are expressed in backer stake, so resting bets are filled
+            // one by one until the incoming stake is covered
+            let mut updated_nonces = ManagedVec::new();
+            for nonce in level.bet_nonces.iter() {
+                let mut matched_bet = self.bet_by_id(nonce).get();
+                let match_this_bet = matched_bet.unmatched_amount.clone().min(remaining.clone());
+
+                if match_this_bet > BigUint::zero() {
+                    matched_bet.matched_amount += &match_this_bet;
+                    matched_bet.unmatched_amount -= &match_this_bet;
+
+                    matched_bet.status = if matched_bet.unmatched_amount == BigUint::zero() {
+                        BetStatus::Matched
                     } else {
-                        if i < levels.len() - 1 {
-                            let last = levels.get(levels.len() - 1);
-                            let _ = levels.set(i, last);
-                        }
-                        levels.remove(levels.len() - 1);
-                    }
-                } else {
-                    i += 1;
+                        BetStatus::PartiallyMatched
+                    };
+
+                    self.bet_by_id(nonce).set(&matched_bet);
+
+                    matched_amount += &match_this_bet;
+                    remaining -= &match_this_bet;
+                    level.total_stake -= &match_this_bet;
+                }
+
+                if matched_bet.unmatched_amount > BigUint::zero() {
+                    updated_nonces.push(nonce);
                 }
+            }
+
+            if updated_nonces.is_empty() {
+                levels.remove(i);
             } else {
+                level.bet_nonces = updated_nonces;
+                let _ = levels.set(i, level);
                 i += 1;
             }
         }
-        
+
         match bet.bet_type {
-            BetType::Back => self.selection_lay_levels(bet.event, bet.selection.id).set(&levels),
-            BetType::Lay => self.selection_back_levels(bet.event, bet.selection.id).set(&levels),
+            BetType::Back => self.store_levels(bet.event, bet.selection.id, BetType::Lay, &levels),
+            BetType::Lay => self.store_levels(bet.event, bet.selection.id, BetType::Back, &levels),
         }
 
         // Update bet state
@@ -183,16 +165,61 @@ pub trait TrackerModule:
             }
         }
 
-        match bet.bet_type {
+        self.store_levels(bet.event, bet.selection.id, bet.bet_type, &levels);
+    }
+
+    fn remove_from_orderbook(&self, bet: &Bet<Self::Api>) {
+        let mut levels = match bet.bet_type {
+            BetType::Back => self.selection_back_levels(bet.event, bet.selection.id).get(),
+            BetType::Lay => self.selection_lay_levels(bet.event, bet.selection.id).get(),
+        };
+
+        for i in 0..levels.len() {
+            let mut level = levels.get(i);
+            if level.odds != bet.odd {
+                continue;
+            }
+
+            let mut remaining_nonces = ManagedVec::new();
+            for nonce in level.bet_nonces.iter() {
+                if nonce != bet.nft_nonce {
+                    remaining_nonces.push(nonce);
+                }
+            }
+
+            if remaining_nonces.is_empty() {
+                levels.remove(i);
+            } else {
+                level.total_stake = if level.total_stake > bet.unmatched_amount {
+                    &level.total_stake - &bet.unmatched_amount
+                } else {
+                    BigUint::zero()
+                };
+                level.bet_nonces = remaining_nonces;
+                let _ = levels.set(i, level);
+            }
+            break;
+        }
+
+        self.store_levels(bet.event, bet.selection.id, bet.bet_type, &levels);
+    }
+
+    fn store_levels(
+        &self,
+        market_id: u64,
+        selection_id: u64,
+        side: BetType,
+        levels: &ManagedVec<Self::Api, PriceLevel<Self::Api>>
+    ) {
+        let liquidity = levels.iter().fold(BigUint::zero(), |acc, level| acc + &level.total_stake);
+        match side {
             BetType::Back => {
-                self.selection_back_levels(bet.event, bet.selection.id).set(&levels);
-                self.selection_back_liquidity(bet.event, bet.selection.id)
-                    .update(|val| *val = levels.iter().fold(BigUint::zero(), |acc, level| acc + &level.total_stake));
+                self.selection_back_levels(market_id, selection_id).set(levels);
+                self.selection_back_liquidity(market_id, selection_id).set(&liquidity);
             },
             BetType::Lay => {
-                self.selection_lay_levels(bet.event, bet.selection.id).set(&levels);
-                self.selection_lay_liquidity(bet.event, bet.selection.id)
-                    .update(|val| *val = levels.iter().fold(BigUint::zero(), |acc, level| acc + &level.total_stake));
+                self.selection_lay_levels(market_id, selection_id).set(levels);
+                self.selection_lay_liquidity(market_id, selection_id).set(&liquidity);
             },
         }
     }
@@ -10,6 +10,8 @@ pub enum BetStatus {
     Win,
     Lost,
     Canceled,
+    Pending,
+    Push,
 }
 
 #[type_abi]
@@ -24,7 +26,10 @@ pub enum BetType {
 pub enum MarketStatus {
     Open,    
     Closed, 
-    Settled
+    Settled,
+    Proposed,
+    Voided,
+    Suspended
 }
 
 
@@ -78,6 +83,23 @@ pub struct Market<M: ManagedTypeApi> {
     pub total_matched_amount: BigUint<M>,
     pub liquidity: BigUint<M>,
     pub created_at: u64,
+    pub market_type_id: u64,
+    pub settlement_token: EgldOrEsdtTokenIdentifier<M>,
+}
+
+/// Market layout written before market types and settlement tokens existed.
+/// Only read while migrating storage on upgrade.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct LegacyMarket<M: ManagedTypeApi> {
+    pub market_id: u64,
+    pub event_id: u64,
+    pub description: ManagedBuffer<M>,
+    pub selections: ManagedVec<M, Selection<M>>,
+    pub close_timestamp: u64,
+    pub market_status: MarketStatus,
+    pub total_matched_amount: BigUint<M>,
+    pub liquidity: BigUint<M>,
+    pub created_at: u64,
 }
 
 #[type_abi]
@@ -111,32 +133,316 @@ pub struct Tracker<M: ManagedTypeApi> {
     pub canceled_count: u64,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
+pub struct BetLimits<M: ManagedTypeApi> {
+    pub min_stake: BigUint<M>,
+    pub max_stake: BigUint<M>,
+    pub max_exposure: BigUint<M>,
+    pub min_odds: u64,
+    pub max_odds: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
+pub struct LimitsOverride<M: ManagedTypeApi> {
+    pub min_stake: Option<BigUint<M>>,
+    pub max_stake: Option<BigUint<M>>,
+    pub max_exposure: Option<BigUint<M>>,
+    pub min_odds: Option<u64>,
+    pub max_odds: Option<u64>,
+}
+
+impl<M: ManagedTypeApi> LimitsOverride<M> {
+    pub fn is_empty(&self) -> bool {
+        self.min_stake.is_none()
+            && self.max_stake.is_none()
+            && self.max_exposure.is_none()
+            && self.min_odds.is_none()
+            && self.max_odds.is_none()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq)]
+pub struct TokenConfig<M: ManagedTypeApi> {
+    pub decimals: u32,
+    pub min_stake: BigUint<M>,
+    pub max_stake: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SolvencyReport<M: ManagedTypeApi> {
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub unmatched_stakes: BigUint<M>,
+    pub matched_liabilities: BigUint<M>,
+    pub unsettled_payouts: BigUint<M>,
+    pub held_bonds: BigUint<M>,
+    pub keeper_bounties: BigUint<M>,
+    pub treasury: BigUint<M>,
+    pub total_obligations: BigUint<M>,
+    pub contract_balance: BigUint<M>,
+    pub is_solvent: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
+pub struct InPlayConfig {
+    pub end_timestamp: u64,
+    pub bet_delay: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum MarketAction {
+    Close,
+    FinalizeResult,
+    ProcessSettlement,
+    ProcessRefunds,
+    RefundUnresulted,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum AuditCheck {
+    BackLiquidity,
+    LayLiquidity,
+    SelectionMatched,
+    MarketMatched,
+}
+
+#[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
-pub enum MarketType {
-    FullTimeResult = 1,
-    TotalGoals = 2,
-    BothTeamsToScore = 3,
-}
-
-impl MarketType {
-    pub fn from_u64(value: u64) -> Self {
-        match value {
-            1 => MarketType::FullTimeResult,
-            2 => MarketType::TotalGoals,
-            3 => MarketType::BothTeamsToScore,
-            _ => panic!("Invalid market type")
+pub struct ResultReport<M: ManagedTypeApi> {
+    /// Score after regular time.
+    pub score_home: u32,
+    pub score_away: u32,
+    pub half_time: Option<Score>,
+    /// Score after extra time, including regular-time goals.
+    pub extra_time: Option<Score>,
+    pub penalties: Option<Score>,
+    /// Selections declared winners by the oracle, for outcome-reported markets.
+    pub winning_selections: ManagedVec<M, u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub struct Score {
+    pub home: u32,
+    pub away: u32,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ProposedResult<M: ManagedTypeApi> {
+    pub report: ResultReport<M>,
+    pub proposed_at: u64,
+    pub challenge_deadline: u64,
+    pub challenge_bond: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Challenge<M: ManagedTypeApi> {
+    pub challenger: ManagedAddress<M>,
+    pub bond: BigUint<M>,
+    pub created_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum Role {
+    Oracle,
+    Operator,
+    Guardian,
+    MarketCreator,
+    TreasuryManager,
+}
+
+/// Configuration change that only takes effect once its timelock expires.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub enum TimelockAction<M: ManagedTypeApi> {
+    GrantRole(Role, ManagedAddress<M>),
+    SetGlobalLimits(BetLimits<M>),
+    SetOracleQuorum(u32),
+    SetDisputeConfig(u64, BigUint<M>),
+    SetTimelockDelay(u64),
+    SetUserLimits(ManagedAddress<M>, LimitsOverride<M>),
+    AddAcceptedToken(EgldOrEsdtTokenIdentifier<M>, TokenConfig<M>),
+    AssignMarketOracle(u64, ManagedAddress<M>),
+    UnassignMarketOracle(u64),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct QueuedAction<M: ManagedTypeApi> {
+    pub action: TimelockAction<M>,
+    pub queued_at: u64,
+    pub eta: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum PauseScope {
+    Betting,
+    Settlement,
+    Claims,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SportEvent<M: ManagedTypeApi> {
+    pub event_id: u64,
+    pub home_team: ManagedBuffer<M>,
+    pub away_team: ManagedBuffer<M>,
+    pub competition_id: u64,
+    pub sport: ManagedBuffer<M>,
+    pub kickoff_timestamp: u64,
+    pub status: EventStatus,
+    pub created_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum EventStatus {
+    Scheduled,
+    Canceled,
+}
+
+/// What happens to an event's markets when its kickoff is moved back.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum PostponePolicy {
+    /// Move every market with the event and keep all bets.
+    KeepBets,
+    /// Keep bets if the new kickoff is within this many hours of the old one,
+    /// otherwise void every unsettled market.
+    VoidAfter(u64),
+    /// Refund unmatched bets, then move the markets with the event.
+    CancelUnmatched,
+}
+
+/// How a market type turns a final score into a winning selection.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum ResolutionRule {
+    /// Home, Away, Draw.
+    MatchResult,
+    /// Over, Under a line in hundredths of a goal (250 = 2.5 goals). Whole
+    /// lines push when the total lands exactly on them; quarter lines split.
+    TotalGoals(u32),
+    /// Yes, No.
+    BothTeamsToScore,
+    /// Home, Away with the home side given the handicap in hundredths of a
+    /// goal (-25 = -0.25). Quarter lines split the stake over the two nearest
+    /// half and whole lines.
+    AsianHandicap(i32),
+    /// Every scoreline up to `max_goals` per side, home goals first
+    /// (0-0, 0-1, ..., 1-0, ...), then one "any other" selection.
+    CorrectScore(u32),
+    /// Home, Away, Draw at half time.
+    HalfTimeResult,
+    /// Half-time result then full-time result, each in Home, Away, Draw order
+    /// (Home/Home, Home/Away, Home/Draw, Away/Home, ...).
+    HalfTimeFullTime,
+    /// Home or Draw, Home or Away, Draw or Away. Two selections win every match.
+    DoubleChance,
+    /// Home, Away; both push on a draw.
+    DrawNoBet,
+    /// Any event with the given number of outcomes. The oracle names the
+    /// winning selections instead of reporting a score.
+    ReportedOutcome(u32),
+}
+
+impl ResolutionRule {
+    pub fn selection_count(&self) -> usize {
+        match self {
+            ResolutionRule::MatchResult => 3,
+            ResolutionRule::TotalGoals(_) => 2,
+            ResolutionRule::BothTeamsToScore => 2,
+            ResolutionRule::AsianHandicap(_) => 2,
+            ResolutionRule::CorrectScore(max_goals) => {
+                // Saturates instead of wrapping on 32-bit targets, so an oversized
+                // grid is still rejected by the selection limit.
+                let per_side = (*max_goals as usize).saturating_add(1);
+                per_side.saturating_mul(per_side).saturating_add(1)
+            },
+            ResolutionRule::HalfTimeResult => 3,
+            ResolutionRule::HalfTimeFullTime => 9,
+            ResolutionRule::DoubleChance => 3,
+            ResolutionRule::DrawNoBet => 2,
+            ResolutionRule::ReportedOutcome(outcomes) => *outcomes as usize,
         }
     }
 
-    pub fn to_description(&self) -> &[u8] {
+    pub fn requires_half_time(&self) -> bool {
+        matches!(self, ResolutionRule::HalfTimeResult | ResolutionRule::HalfTimeFullTime)
+    }
+
+    pub fn is_valid(&self) -> bool {
         match self {
-            MarketType::FullTimeResult => b"FullTime Result",
-            MarketType::TotalGoals => b"Total Goals O/U 2.5",
-            MarketType::BothTeamsToScore => b"Both Teams To Score",
+            ResolutionRule::TotalGoals(line) => line % 25 == 0,
+            ResolutionRule::AsianHandicap(line) => line % 25 == 0,
+            ResolutionRule::ReportedOutcome(outcomes) => *outcomes >= 2,
+            _ => true,
         }
     }
 }
 
+/// What a settled market means for one selection.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum SelectionOutcome {
+    Win,
+    Lose,
+    Push,
+    HalfWin,
+    HalfLose,
+}
+
+impl SelectionOutcome {
+    /// Outcome for a selection that beat its line by `margin` hundredths of a
+    /// goal. Quarter lines settle half the stake on each neighbouring line.
+    pub fn from_margin(margin: i64) -> Self {
+        if margin % 50 != 0 {
+            return match (Self::from_margin(margin - 25), Self::from_margin(margin + 25)) {
+                (SelectionOutcome::Push, SelectionOutcome::Win) => SelectionOutcome::HalfWin,
+                (SelectionOutcome::Lose, SelectionOutcome::Push) => SelectionOutcome::HalfLose,
+                (lower, _) => lower,
+            };
+        }
+
+        if margin > 0 {
+            SelectionOutcome::Win
+        } else if margin < 0 {
+            SelectionOutcome::Lose
+        } else {
+            SelectionOutcome::Push
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            SelectionOutcome::Win => SelectionOutcome::Lose,
+            SelectionOutcome::Lose => SelectionOutcome::Win,
+            SelectionOutcome::Push => SelectionOutcome::Push,
+            SelectionOutcome::HalfWin => SelectionOutcome::HalfLose,
+            SelectionOutcome::HalfLose => SelectionOutcome::HalfWin,
+        }
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MarketTypeConfig<M: ManagedTypeApi> {
+    pub id: u64,
+    pub description: ManagedBuffer<M>,
+    pub selection_labels: ManagedVec<M, ManagedBuffer<M>>,
+    pub rule: ResolutionRule,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone)]
 pub enum ProcessingStatus {
@@ -1,5 +1,5 @@
-use crate::constants::constants;
-use crate::types::{Market, MarketStatus};
+use crate::errors::{ERR_INVALID_MARKET_TOKEN, ERR_MARKET_NOT_OPEN, ERR_MAXIMUM_STAKE, ERR_ODDS_OUT_OF_RANGE, ERR_STAKE_OUT_OF_RANGE};
+use crate::types::{BetLimits, Market, MarketStatus};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -7,26 +7,37 @@ multiversx_sc::derive_imports!();
 #[multiversx_sc::module]
 pub trait ValidationModule:
     crate::storage::StorageModule +
-    crate::events::EventsModule
+    crate::events::EventsModule +
+    crate::tokens::TokensModule +
+    crate::timelock::TimelockModule
 {
     //--------------------------------------------------------------------------------------------//
     //-------------------------------- Bet Validation --------------------------------------------//
     //--------------------------------------------------------------------------------------------//    
 
-    fn validate_bet_amount(&self, total_amount: &BigUint) {
-        let one_token = BigUint::from(1_000_000_000_000_000_000u64);
-        let tokens = total_amount / &one_token;
+    fn validate_bet_amount(
+        &self,
+        token: &EgldOrEsdtTokenIdentifier,
+        total_amount: &BigUint,
+        limits: &BetLimits<Self::Api>
+    ) {
+        let token_config = self.require_accepted_token(token);
+        require!(
+            total_amount >= &token_config.min_stake && total_amount <= &token_config.max_stake,
+            ERR_STAKE_OUT_OF_RANGE
+        );
 
+        let normalized_amount = self.to_normalized_amount(token, total_amount);
         require!(
-            tokens >= BigUint::from(1u32) && tokens <= BigUint::from(10000u32),
-            "Stake amount out of range"
+            normalized_amount >= limits.min_stake && normalized_amount <= limits.max_stake,
+            ERR_STAKE_OUT_OF_RANGE
         );
     }
 
-    fn validate_bet_odds(&self, odds: &BigUint) {
+    fn validate_bet_odds(&self, odds: &BigUint, limits: &BetLimits<Self::Api>) {
         require!(
-            odds >= &BigUint::from(101u32) && odds <= &BigUint::from(100000u32),
-            "Odds out of range"
+            odds >= &BigUint::from(limits.min_odds) && odds <= &BigUint::from(limits.max_odds),
+            ERR_ODDS_OUT_OF_RANGE
         );
     }
     
@@ -87,7 +98,34 @@ pub trait ValidationModule:
         let market = self.markets(market_id).get();
         let created_at = self.blockchain().get_block_timestamp();
         
-        require!(created_at < market.close_timestamp, "Market already closed");
+        require!(market.market_status == MarketStatus::Open, ERR_MARKET_NOT_OPEN);
+        require!(created_at < self.betting_close_timestamp(market_id), "Market already closed");
+    }
+
+    /// Kickoff for regular markets, the end of the in-play phase otherwise.
+    fn betting_close_timestamp(&self, market_id: u64) -> u64 {
+        if self.market_in_play(market_id).is_empty() {
+            return self.markets(market_id).get().close_timestamp;
+        }
+        self.market_in_play(market_id).get().end_timestamp
+    }
+
+    fn is_in_play_phase(&self, market_id: u64) -> bool {
+        !self.market_in_play(market_id).is_empty()
+            && self.blockchain().get_block_timestamp() >= self.markets(market_id).get().close_timestamp
+    }
+
+    fn validate_market_token(
+        &self,
+        market_id: u64,
+        token: &EgldOrEsdtTokenIdentifier,
+        token_nonce: u64
+    ) {
+        let market = self.markets(market_id).get();
+        require!(
+            &market.settlement_token == token && token_nonce == 0,
+            ERR_INVALID_MARKET_TOKEN
+        );
     }
 
     fn validate_selection(&self, market_id: u64, selection_id: u64) {
@@ -103,15 +141,13 @@ pub trait ValidationModule:
     fn validate_user_exposure(
         &self,
         user: &ManagedAddress<Self::Api>,
-        stake: &BigUint
+        exposure: &BigUint,
+        limits: &BetLimits<Self::Api>
     ) {
         let current_exposure = self.user_total_exposure(user).get();
-        let new_exposure = &current_exposure + stake;
+        let new_exposure = &current_exposure + exposure;
         
-        require!(
-            new_exposure <= BigUint::from(constants::MAX_USER_EXPOSURE),
-            "Maximum stake exceeded"
-        );
+        require!(new_exposure <= limits.max_exposure, ERR_MAXIMUM_STAKE);
     }
 
     fn get_next_market_id(&self) -> u64 {